@@ -1,20 +1,35 @@
 // components
 pub mod batter_select;
 pub mod bowler_select;
+pub mod correction_select;
+pub mod crease_select;
 pub mod event_edit;
 pub mod extra_select;
 pub mod innings_select;
+pub mod keeper_select;
+pub mod match_config_entry;
+pub mod penalty_select;
+pub mod retire_select;
 pub mod runs_button;
+pub mod runs_select;
 pub mod start;
+pub mod substitution_select;
 pub mod team_entry;
 pub mod wicket_select;
 
 use batter_select::BatterSelectEvent;
 use bowler_select::BowlerSelectEvent;
+use crease_select::CreaseSelectEvent;
 use extra_select::ExtraSelectEvent;
 use innings_select::InningsSelectEvent;
+use keeper_select::KeeperSelectEvent;
+use match_config_entry::MatchConfigEntryEvent;
+use penalty_select::PenaltySelectEvent;
+use retire_select::RetireSelectEvent;
 use runs_button::RunsButtonEvent;
+use runs_select::RunsSelectEvent;
 use start::StartEvent;
+use substitution_select::SubstitutionSelectEvent;
 use team_entry::TeamEntryEvent;
 use wicket_select::WicketSelectEvent;
 
@@ -49,6 +64,13 @@ pub enum ComponentEvent {
     InningsSelectEvent(InningsSelectEvent),
     SubcomponentEvent(SubcomponentEvent),
     EventEditEvent(EventEditEvent),
+    MatchConfigEntryEvent(MatchConfigEntryEvent),
+    PenaltySelectEvent(PenaltySelectEvent),
+    RetireSelectEvent(RetireSelectEvent),
+    KeeperSelectEvent(KeeperSelectEvent),
+    RunsSelectEvent(RunsSelectEvent),
+    SubstitutionSelectEvent(SubstitutionSelectEvent),
+    CreaseSelectEvent(CreaseSelectEvent),
 }
 
 pub trait Subcomponent<T> {
@@ -30,23 +30,21 @@ impl Component for BatterSelect {
             BatterSelectEvent::BatterSelected(order) => self.selected_player = Some(order),
             BatterSelectEvent::SubmitBatter => {
                 if let Some(batter) = game_state.batter_to_replace() {
-                    match batter {
-                        ReplaceBatter::OnStrike => {
-                            game_state.update(GameEvent::SelectOnStrike(
-                                self.selected_player.expect("Selected player should exist"),
-                            ));
-                        }
-                        ReplaceBatter::OffStrike => {
-                            game_state.update(GameEvent::SelectOffStrike(
-                                self.selected_player.expect("Selected player should exist"),
-                            ));
-                        }
-                    }
+                    // a batter selected while correcting the log moves on to
+                    // whatever the corrected log needs next
+                    page = match batter {
+                        ReplaceBatter::OnStrike => game_state.update(GameEvent::SelectOnStrike(
+                            self.selected_player.expect("Selected player should exist"),
+                        )),
+                        ReplaceBatter::OffStrike => game_state.update(GameEvent::SelectOffStrike(
+                            self.selected_player.expect("Selected player should exist"),
+                        )),
+                    };
 
                     self.selected_player = None;
                 }
 
-                if let None = game_state.batter_to_replace() {
+                if page.is_none() && game_state.batter_to_replace().is_none() {
                     page = match game_state.bowler {
                         Some(_) => Some(Page::Scoring),
                         None => Some(Page::SelectBowler),
@@ -82,10 +80,8 @@ impl BatterSelect {
 
         for player in &team.players {
             let player = player.borrow();
-            if (player.how_out != HowOut::DidNotBat
-                && player.how_out != HowOut::RetiredHurt
-                && player.how_out != HowOut::RetiredNotOut)
-                || player.how_out == HowOut::NotOut
+            if (player.how_out != HowOut::DidNotBat && !player.how_out.is_retirement())
+                || player.reserve
             {
                 continue;
             }
@@ -109,6 +105,13 @@ impl BatterSelect {
     fn select_on_strike_batter<'a>(&self, game_state: &'a GameState) -> Element<'a, Event> {
         let mut column = column![text("Select on strike batter")];
         column = column.push(self.select_batter(game_state));
+
+        if game_state.can_forfeit() {
+            column = column.push(
+                button("Forfeit innings").on_press(Event::GameEvent(GameEvent::ForfeitInnings)),
+            );
+        }
+
         column.into()
     }
 
@@ -4,7 +4,7 @@ use crate::state::game_state::team::player::Player;
 use crate::state::event::Event;
 use crate::state::game_state::GameState;
 use crate::state::Page;
-use iced::widget::{button, radio, text, Column, Radio};
+use iced::widget::{button, radio, text, Column};
 use iced::Element;
 use macros::AsComponentEvent;
 use std::cell::RefCell;
@@ -12,6 +12,7 @@ use std::rc::Rc;
 
 pub struct BowlerSelect {
     selected_player: Option<usize>,
+    replacing: bool,
 }
 
 impl Component for BowlerSelect {
@@ -30,9 +31,14 @@ impl Component for BowlerSelect {
         match event {
             BowlerSelectEvent::BowlerSelected(order) => self.selected_player = Some(order),
             BowlerSelectEvent::SubmitBowler => {
-                game_state.update(GameEvent::SelectBowler(
-                    self.selected_player.expect("Selected player should exist"),
-                ));
+                let player = self.selected_player.expect("Selected player should exist");
+
+                if self.replacing {
+                    game_state.update(GameEvent::ReplaceBowler(player));
+                } else {
+                    game_state.update(GameEvent::SelectBowler(player));
+                }
+
                 page = Some(Page::Scoring);
             }
         }
@@ -41,30 +47,47 @@ impl Component for BowlerSelect {
     }
 
     fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
-        match game_state.bowler {
-            None => self.select_bowler(game_state),
-            Some(_) => panic!("There should be a bowler to select when on this page"),
+        match (&game_state.bowler, self.replacing) {
+            (None, false) | (Some(_), true) => self.select_bowler(game_state),
+            _ => panic!("There should be a bowler to select when on this page"),
         }
     }
 }
 
 impl BowlerSelect {
     pub fn new(game_state: &GameState) -> Self {
+        let selected_player = game_state
+            .last_last_bowler
+            .filter(|bowler| game_state.bowler_restriction(*bowler).is_none());
+
         BowlerSelect {
-            selected_player: game_state.last_last_bowler,
+            selected_player,
+            replacing: false,
+        }
+    }
+
+    // finishing an over for a bowler who can't continue
+    pub fn replacement() -> Self {
+        BowlerSelect {
+            selected_player: None,
+            replacing: true,
         }
     }
 
     fn select_bowler<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
         let team = game_state.bowling_team();
         let mut column = Column::new();
-        column = column.push(text("Select bowler"));
+        column = column.push(text(if self.replacing {
+            "Select bowler to finish the over"
+        } else {
+            "Select bowler"
+        }));
 
         let bowled_players = team.bowled_players_in_order();
 
         if bowled_players.len() > 0 {
             for player in bowled_players {
-                column = column.push(self.display_bowler(&player));
+                column = column.push(self.display_bowler(game_state, &player));
             }
         }
 
@@ -73,7 +96,7 @@ impl BowlerSelect {
         if not_bowled_players.len() > 0 {
             column = column.push("Players that haven't bowled yet:");
             for player in not_bowled_players {
-                column = column.push(self.display_bowler(&player));
+                column = column.push(self.display_bowler(game_state, &player));
             }
         }
 
@@ -82,10 +105,18 @@ impl BowlerSelect {
                 .push(button("Select player").on_press(BowlerSelectEvent::SubmitBowler.as_event()));
         }
 
+        if self.replacing {
+            column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+        }
+
         column.into()
     }
 
-    fn display_bowler(&self, player: &Rc<RefCell<Player>>) -> Radio<Event> {
+    fn display_bowler<'a>(
+        &self,
+        game_state: &GameState,
+        player: &Rc<RefCell<Player>>,
+    ) -> Element<'a, Event> {
         let player = player.borrow();
         let mut label = player.to_string();
 
@@ -99,9 +130,15 @@ impl BowlerSelect {
             );
         }
 
+        // players who can't bowl are listed but can't be selected
+        if let Some(restriction) = game_state.bowler_restriction(player.order) {
+            return text(format!("{label} - {restriction}")).into();
+        }
+
         radio(label, player.order, self.selected_player, |selection| {
             BowlerSelectEvent::BowlerSelected(selection).as_event()
         })
+        .into()
     }
 }
 
@@ -0,0 +1,64 @@
+use crate::components::{Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::GameState;
+use crate::state::Page;
+use iced::widget::{button, column, row, text};
+use iced::Element;
+
+// the state passed in has been rebuilt up to the delivery being corrected, so
+// the usual runs, extra and wicket pages show the batters and bowler as they were
+pub struct CorrectionSelect {}
+
+impl Component for CorrectionSelect {
+    fn update(&mut self, _: ComponentEvent, game_state: GameState) -> (GameState, Option<Page>) {
+        (game_state, None)
+    }
+
+    fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
+        let correction = game_state
+            .correction
+            .as_ref()
+            .expect("There should be a delivery to correct on this page");
+
+        let mut column = column![text(match correction.insert {
+            true => "Insert a ball before:",
+            false => "Replace delivery:",
+        })];
+
+        if let Some(event_container) = correction.events[correction.event_index].to_container() {
+            column = column.push(event_container);
+        }
+
+        if let (Some(batter), Some(bowler)) = (game_state.on_strike_batter(), &game_state.bowler) {
+            column = column.push(text(format!(
+                "{bowler} to {batter}",
+                bowler = bowler.borrow(),
+                batter = batter.borrow()
+            )));
+        }
+
+        column = column.push(row![
+            button("runs").on_press(Event::ChangePage(Page::SelectRuns)),
+            button("extra").on_press(Event::ChangePage(Page::SelectExtra)),
+            button("wicket").on_press(Event::ChangePage(Page::SelectWicket)),
+        ]);
+        column = column.push(text(
+            "Events after this one that no longer apply will be skipped and shown as warnings",
+        ));
+        column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+
+        column.into()
+    }
+}
+
+impl CorrectionSelect {
+    pub fn new() -> Self {
+        CorrectionSelect {}
+    }
+}
+
+impl Default for CorrectionSelect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,115 @@
+use crate::components::{AsEvent, Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::event::GameEvent;
+use crate::state::game_state::wickets::HowOut;
+use crate::state::game_state::{BatterCorrection, GameState, PlayerType};
+use crate::state::Page;
+use iced::widget::{button, column, radio, text};
+use iced::Element;
+use macros::AsComponentEvent;
+
+pub struct CreaseSelect {
+    selected_batter: Option<usize>,
+    selected_player: Option<usize>,
+}
+
+impl Component for CreaseSelect {
+    fn update(
+        &mut self,
+        event: ComponentEvent,
+        mut game_state: GameState,
+    ) -> (GameState, Option<Page>) {
+        let event = match event {
+            ComponentEvent::CreaseSelectEvent(crease_select_event) => crease_select_event,
+            _ => panic!("Crease select component has been called with an event that is not a crease select event!")
+        };
+
+        let mut page = None;
+
+        match event {
+            CreaseSelectEvent::BatterSelected(batter) => self.selected_batter = Some(batter),
+            CreaseSelectEvent::PlayerSelected(order) => self.selected_player = Some(order),
+            CreaseSelectEvent::SubmitCorrection => {
+                let batter = match self.selected_batter {
+                    Some(0) => PlayerType::A,
+                    _ => PlayerType::B,
+                };
+
+                game_state.update(GameEvent::CorrectBatter(BatterCorrection::new(
+                    batter,
+                    self.selected_player.expect("Selected player should exist"),
+                )));
+                page = Some(Page::Scoring);
+            }
+        }
+
+        (game_state, page)
+    }
+
+    fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
+        let mut column = column![text("Batter entered:")];
+
+        for (i, batter) in [&game_state.batter_a, &game_state.batter_b]
+            .into_iter()
+            .enumerate()
+        {
+            if let Some(batter) = batter {
+                column = column.push(radio(
+                    batter.borrow().to_string(),
+                    i,
+                    self.selected_batter,
+                    |selection| CreaseSelectEvent::BatterSelected(selection).as_event(),
+                ));
+            }
+        }
+
+        column = column.push(text("Player actually at the crease:"));
+
+        for player in &game_state.batting_team().players {
+            let player = player.borrow();
+
+            if player.how_out != HowOut::DidNotBat || player.reserve {
+                continue;
+            }
+
+            column = column.push(radio(
+                player.to_string(),
+                player.order,
+                self.selected_player,
+                |selection| CreaseSelectEvent::PlayerSelected(selection).as_event(),
+            ));
+        }
+
+        if self.selected_batter.is_some() && self.selected_player.is_some() {
+            column = column.push(
+                button("Correct batter").on_press(CreaseSelectEvent::SubmitCorrection.as_event()),
+            );
+        }
+
+        column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+
+        column.into()
+    }
+}
+
+impl CreaseSelect {
+    pub fn new() -> Self {
+        CreaseSelect {
+            selected_batter: None,
+            selected_player: None,
+        }
+    }
+}
+
+impl Default for CreaseSelect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, AsComponentEvent)]
+pub enum CreaseSelectEvent {
+    BatterSelected(usize),
+    PlayerSelected(usize),
+    SubmitCorrection,
+}
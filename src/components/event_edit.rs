@@ -16,11 +16,21 @@ impl EventEdit {
     }
 
     pub fn to_element<'a>(&'a self) -> iced::Element<'a, crate::state::event::Event> {
-        let edit_options = [EventEditEvent::EventDeleted(self.event_index)];
-
-        pick_list(edit_options, None::<EventEditEvent>, |_| {
-            Event::DeleteEvent(self.event_index)
-        })
+        let edit_options = [
+            EventEditEvent::EventEdited(self.event_index),
+            EventEditEvent::BallInserted(self.event_index),
+            EventEditEvent::EventDeleted(self.event_index),
+        ];
+
+        pick_list(
+            edit_options,
+            None::<EventEditEvent>,
+            |selection| match selection {
+                EventEditEvent::EventEdited(index) => Event::EditDelivery(index),
+                EventEditEvent::BallInserted(index) => Event::InsertDelivery(index),
+                EventEditEvent::EventDeleted(index) => Event::DeleteEvent(index),
+            },
+        )
         .placeholder("...")
         .into()
     }
@@ -28,12 +38,16 @@ impl EventEdit {
 
 #[derive(Clone, Debug, AsComponentEvent, PartialEq)]
 pub enum EventEditEvent {
+    EventEdited(usize),
+    BallInserted(usize),
     EventDeleted(usize),
 }
 
 impl std::fmt::Display for EventEditEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match *self {
+            EventEditEvent::EventEdited(_) => "Edit",
+            EventEditEvent::BallInserted(_) => "Insert ball before",
             EventEditEvent::EventDeleted(_) => "Delete",
         })
     }
@@ -1,7 +1,8 @@
 use crate::components::runs_button::RunsButton;
 use crate::components::{AsEvent, Component, ComponentEvent};
 use crate::state::game_state::event::GameEvent;
-use crate::state::game_state::extras::{Extra, ExtraType};
+use crate::state::game_state::extras::{ByeType, Extra, ExtraType};
+use crate::state::game_state::runs::Boundary;
 use crate::state::event::Event;
 use crate::state::game_state::GameState;
 use crate::state::Page;
@@ -14,6 +15,8 @@ pub struct ExtraSelect {
     selected_extra: Option<usize>,
     runs_button: Option<RunsButton>,
     runs_button_runs: u32,
+    selected_byes: Option<usize>,
+    selected_boundary: Option<usize>,
 }
 
 impl Component for ExtraSelect {
@@ -46,6 +49,13 @@ impl Component for ExtraSelect {
                     _ => 1,
                 };
                 self.runs_button = Some(RunsButton::new(minimum_runs));
+                self.runs_button_runs = minimum_runs;
+                self.selected_byes = Some(0);
+                self.selected_boundary = Some(0);
+            }
+            ExtraSelectEvent::ByesSelected(byes_index) => self.selected_byes = Some(byes_index),
+            ExtraSelectEvent::BoundarySelected(boundary_index) => {
+                self.selected_boundary = Some(boundary_index)
             }
             ExtraSelectEvent::SubmitExtra => {
                 let extra_type = ExtraType::iter().collect::<Vec<ExtraType>>()[self
@@ -53,10 +63,19 @@ impl Component for ExtraSelect {
                     .expect("Extra should be selected when an extra is submitted")]
                 .clone();
 
-                let extra = Extra::new(self.runs_button_runs, extra_type);
+                let mut extra = Extra::new(self.runs_button_runs, extra_type);
+
+                if let Some(byes) = self.selected_bye_type() {
+                    extra = extra.with_byes(byes);
+                }
+
+                if let Some(boundary) = self.boundary() {
+                    extra = extra.with_boundary(boundary);
+                }
 
-                game_state.update(GameEvent::Extra(extra));
-                page = Some(Page::Scoring);
+                page = game_state
+                    .update(GameEvent::Extra(extra))
+                    .or(Some(Page::Scoring));
             }
         }
 
@@ -64,6 +83,8 @@ impl Component for ExtraSelect {
     }
 
     fn view<'a>(&'a self, _: &'a GameState) -> Element<'a, Event> {
+        let extra_types = ExtraType::iter().collect::<Vec<ExtraType>>();
+
         let mut column = column![text("Select extra:")];
 
         for (i, extra) in ExtraType::iter().enumerate() {
@@ -75,13 +96,62 @@ impl Component for ExtraSelect {
             ));
         }
 
-        if let Some(_) = self.selected_extra {
-            column = column.push(
-                self.runs_button
-                    .as_ref()
-                    .expect("Runs button should exist if extra is selected")
-                    .view(),
-            );
+        if let Some(extra_index) = self.selected_extra {
+            let runs_label = match extra_types[extra_index] {
+                ExtraType::Wide => Some("Wides"),
+                ExtraType::NoBall => Some("Off the bat"),
+                _ => None,
+            };
+
+            // runs on a wide or no ball can also be byes or leg byes
+            if let Some(runs_label) = runs_label {
+                column = column.push(text("Runs scored as:"));
+                column = column.push(radio(runs_label, 0, self.selected_byes, |selection| {
+                    ExtraSelectEvent::ByesSelected(selection).as_event()
+                }));
+
+                for (i, bye_type) in ByeType::iter().enumerate() {
+                    if extra_types[extra_index] == ExtraType::Wide && bye_type == ByeType::LegBye {
+                        continue;
+                    }
+
+                    column = column.push(radio(
+                        format!("{bye_type}s"),
+                        i + 1,
+                        self.selected_byes,
+                        |selection| ExtraSelectEvent::ByesSelected(selection).as_event(),
+                    ));
+                }
+            }
+
+            column = column.push(text("Boundary:"));
+            column = column.push(radio("None", 0, self.selected_boundary, |selection| {
+                ExtraSelectEvent::BoundarySelected(selection).as_event()
+            }));
+
+            for (i, boundary) in [Boundary::Four, Boundary::Six].iter().enumerate() {
+                // only a no ball can be hit for six
+                if *boundary == Boundary::Six && extra_types[extra_index] != ExtraType::NoBall {
+                    continue;
+                }
+
+                column = column.push(radio(
+                    boundary.to_string(),
+                    i + 1,
+                    self.selected_boundary,
+                    |selection| ExtraSelectEvent::BoundarySelected(selection).as_event(),
+                ));
+            }
+
+            // the boundary decides the runs, so only ask for runs without one
+            if self.boundary().is_none() {
+                column = column.push(
+                    self.runs_button
+                        .as_ref()
+                        .expect("Runs button should exist if extra is selected")
+                        .view(),
+                );
+            }
             column = column
                 .push(button("Select extra").on_press(ExtraSelectEvent::SubmitExtra.as_event()));
         }
@@ -96,6 +166,22 @@ impl ExtraSelect {
             selected_extra: None,
             runs_button: None,
             runs_button_runs: 0,
+            selected_byes: None,
+            selected_boundary: None,
+        }
+    }
+
+    fn selected_bye_type(&self) -> Option<ByeType> {
+        let byes_index = self.selected_byes?.checked_sub(1)?;
+
+        ByeType::iter().nth(byes_index)
+    }
+
+    fn boundary(&self) -> Option<Boundary> {
+        match self.selected_boundary? {
+            1 => Some(Boundary::Four),
+            2 => Some(Boundary::Six),
+            _ => None,
         }
     }
 }
@@ -103,5 +189,7 @@ impl ExtraSelect {
 #[derive(Clone, Debug, AsComponentEvent)]
 pub enum ExtraSelectEvent {
     ExtraSelected(usize),
+    ByesSelected(usize),
+    BoundarySelected(usize),
     SubmitExtra,
 }
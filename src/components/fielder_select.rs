@@ -16,6 +16,7 @@ pub struct FielderSelect {
     players: Vec<Rc<RefCell<Player>>>,
     selected_player: Option<usize>,
     selection_fn: Box<dyn Fn(usize) -> Event>,
+    value_fn: fn(usize) -> WicketSubcomponentData,
     message: Option<String>,
 }
 
@@ -63,9 +64,7 @@ impl Subcomponent<WicketSubcomponentData> for FielderSelect {
     }
 
     fn get_value(&self) -> Option<WicketSubcomponentData> {
-        Some(WicketSubcomponentData::CaughtSubcomponent(
-            self.selected_player?,
-        ))
+        Some((self.value_fn)(self.selected_player?))
     }
 }
 
@@ -77,6 +76,7 @@ impl FielderSelect {
             selection_fn: Box::new(|selection| {
                 FielderSelectEvent::FielderSelected(selection).as_event()
             }),
+            value_fn: WicketSubcomponentData::CaughtSubcomponent,
             message: None,
         }
     }
@@ -89,6 +89,7 @@ impl FielderSelect {
             selected_player: None,
             players,
             selection_fn: selection_fn,
+            value_fn: WicketSubcomponentData::CaughtSubcomponent,
             message: None,
         }
     }
@@ -97,6 +98,11 @@ impl FielderSelect {
         self.selected_player = Some(order);
     }
 
+    pub fn with_value_fn(mut self, value_fn: fn(usize) -> WicketSubcomponentData) -> Self {
+        self.value_fn = value_fn;
+        self
+    }
+
     pub fn with_message(mut self, message: String) -> Self {
         self.message = Some(message);
         self
@@ -41,20 +41,36 @@ impl Component for InningsSelect {
     }
 
     fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
+        let (message, team_a_label, team_b_label) = if game_state.is_follow_on_available() {
+            let follow_on_label = |team_type: TeamType, team_name: &String| {
+                if game_state.last_innings_team() == Some(team_type) {
+                    format!("{team_name} follow on")
+                } else {
+                    format!("{team_name} bat again")
+                }
+            };
+
+            (
+                "Enforce the follow-on?",
+                follow_on_label(TeamType::A, &game_state.team_a.team_name),
+                follow_on_label(TeamType::B, &game_state.team_b.team_name),
+            )
+        } else {
+            (
+                "Select first innings:",
+                game_state.team_a.team_name.clone(),
+                game_state.team_b.team_name.clone(),
+            )
+        };
+
         let mut column = column![
-            text("Select first innings:"),
-            radio(
-                &game_state.team_a.team_name,
-                0,
-                self.selected_team,
-                |selection| InningsSelectEvent::InningsSelected(selection).as_event(),
-            ),
-            radio(
-                &game_state.team_b.team_name,
-                1,
-                self.selected_team,
-                |selection| InningsSelectEvent::InningsSelected(selection).as_event(),
-            ),
+            text(message),
+            radio(team_a_label, 0, self.selected_team, |selection| {
+                InningsSelectEvent::InningsSelected(selection).as_event()
+            }),
+            radio(team_b_label, 1, self.selected_team, |selection| {
+                InningsSelectEvent::InningsSelected(selection).as_event()
+            }),
         ];
 
         if self.selected_team != None {
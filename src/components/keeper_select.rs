@@ -0,0 +1,112 @@
+use crate::components::{AsEvent, Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::event::GameEvent;
+use crate::state::game_state::GameState;
+use crate::state::Page;
+use iced::widget::{button, column, radio, text};
+use iced::Element;
+use macros::AsComponentEvent;
+
+// changes the captain and wicketkeeper of the fielding side
+pub struct KeeperSelect {
+    selected_player: Option<usize>,
+    selected_captain: Option<usize>,
+}
+
+impl Component for KeeperSelect {
+    fn update(
+        &mut self,
+        event: ComponentEvent,
+        mut game_state: GameState,
+    ) -> (GameState, Option<Page>) {
+        let event = match event {
+            ComponentEvent::KeeperSelectEvent(keeper_select_event) => keeper_select_event,
+            _ => panic!("Keeper Select component has been called with an event that is not a keeper select event!")
+        };
+
+        let mut page = None;
+
+        match event {
+            KeeperSelectEvent::KeeperSelected(order) => self.selected_player = Some(order),
+            KeeperSelectEvent::CaptainSelected(order) => self.selected_captain = Some(order),
+            KeeperSelectEvent::SubmitKeeper => {
+                let team = game_state.bowling_team();
+                let keeper = team.wicket_keeper().map(|player| player.borrow().order);
+                let captain = team.captain().map(|player| player.borrow().order);
+
+                if let Some(order) = self.selected_player.filter(|order| keeper != Some(*order)) {
+                    game_state.update(GameEvent::ChangeKeeper(order));
+                }
+
+                if let Some(order) = self
+                    .selected_captain
+                    .filter(|order| captain != Some(*order))
+                {
+                    game_state.update(GameEvent::ChangeCaptain(order));
+                }
+
+                page = Some(Page::Scoring);
+            }
+        }
+
+        (game_state, page)
+    }
+
+    fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
+        let players = game_state.bowling_team().playing_players();
+        let mut column = column![text("Select wicketkeeper:")];
+
+        for player in &players {
+            let player = player.borrow();
+
+            column = column.push(radio(
+                player.to_string(),
+                player.order,
+                self.selected_player,
+                |selection| KeeperSelectEvent::KeeperSelected(selection).as_event(),
+            ));
+        }
+
+        column = column.push(text("Select captain:"));
+
+        for player in &players {
+            let player = player.borrow();
+
+            column = column.push(radio(
+                player.to_string(),
+                player.order,
+                self.selected_captain,
+                |selection| KeeperSelectEvent::CaptainSelected(selection).as_event(),
+            ));
+        }
+
+        if self.selected_player.is_some() || self.selected_captain.is_some() {
+            column = column
+                .push(button("Change roles").on_press(KeeperSelectEvent::SubmitKeeper.as_event()));
+        }
+
+        column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+
+        column.into()
+    }
+}
+
+impl KeeperSelect {
+    pub fn new(game_state: &GameState) -> Self {
+        let team = game_state.bowling_team();
+        let selected_player = team.wicket_keeper().map(|player| player.borrow().order);
+        let selected_captain = team.captain().map(|player| player.borrow().order);
+
+        KeeperSelect {
+            selected_player,
+            selected_captain,
+        }
+    }
+}
+
+#[derive(Clone, Debug, AsComponentEvent)]
+pub enum KeeperSelectEvent {
+    KeeperSelected(usize),
+    CaptainSelected(usize),
+    SubmitKeeper,
+}
@@ -0,0 +1,228 @@
+use crate::components::{AsEvent, Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::event::GameEvent;
+use crate::state::game_state::match_config::{MatchConfig, MatchFormat};
+use crate::state::game_state::GameState;
+use crate::state::Page;
+use iced::widget::{button, checkbox, column, radio, row, text, text_input};
+use iced::Element;
+use macros::AsComponentEvent;
+use strum::IntoEnumIterator;
+
+pub struct MatchConfigEntry {
+    selected_format: Option<usize>,
+    overs_input: String,
+    balls_input: String,
+    players_input: String,
+    wickets_input: String,
+    innings_input: String,
+    follow_on_input: String,
+    max_overs_input: String,
+    free_hits: bool,
+}
+
+impl Component for MatchConfigEntry {
+    fn update(
+        &mut self,
+        event: ComponentEvent,
+        mut game_state: GameState,
+    ) -> (GameState, Option<Page>) {
+        let event = match event {
+            ComponentEvent::MatchConfigEntryEvent(match_config_entry_event) => match_config_entry_event,
+            _ => panic!("Match Config Entry has been called with an event that is not a match config entry event!"),
+        };
+
+        let mut page = None;
+
+        match event {
+            MatchConfigEntryEvent::FormatSelected(format_index) => {
+                self.selected_format = Some(format_index);
+                let format = &MatchFormat::iter().collect::<Vec<MatchFormat>>()[format_index];
+                self.set_inputs(&format.config());
+            }
+            MatchConfigEntryEvent::OversChanged(overs) => self.overs_input = overs,
+            MatchConfigEntryEvent::BallsChanged(balls) => self.balls_input = balls,
+            MatchConfigEntryEvent::PlayersChanged(players) => self.players_input = players,
+            MatchConfigEntryEvent::WicketsChanged(wickets) => self.wickets_input = wickets,
+            MatchConfigEntryEvent::InningsChanged(innings) => self.innings_input = innings,
+            MatchConfigEntryEvent::FollowOnChanged(follow_on) => self.follow_on_input = follow_on,
+            MatchConfigEntryEvent::MaxOversChanged(max_overs) => self.max_overs_input = max_overs,
+            MatchConfigEntryEvent::FreeHitsToggled(free_hits) => self.free_hits = free_hits,
+            MatchConfigEntryEvent::SubmitConfig => {
+                let match_config = self
+                    .match_config()
+                    .expect("Match config should be valid when it is submitted");
+
+                game_state.update(GameEvent::StartGame(match_config));
+                page = Some(Page::TeamEntry);
+            }
+        }
+
+        (game_state, page)
+    }
+
+    fn view<'a>(&'a self, _: &'a GameState) -> Element<'a, Event> {
+        let mut column = column![text("Select match format:")];
+
+        for (i, format) in MatchFormat::iter().enumerate() {
+            column = column.push(radio(
+                format.to_string(),
+                i,
+                self.selected_format,
+                |selection| MatchConfigEntryEvent::FormatSelected(selection).as_event(),
+            ));
+        }
+
+        column = column.push(row![
+            text("Overs per innings (blank for unlimited): "),
+            text_input("Overs", &self.overs_input)
+                .on_input(|input| MatchConfigEntryEvent::OversChanged(input).as_event()),
+        ]);
+        column = column.push(row![
+            text("Balls per over: "),
+            text_input("Balls", &self.balls_input)
+                .on_input(|input| MatchConfigEntryEvent::BallsChanged(input).as_event()),
+        ]);
+        column = column.push(row![
+            text("Players per side: "),
+            text_input("Players", &self.players_input)
+                .on_input(|input| MatchConfigEntryEvent::PlayersChanged(input).as_event()),
+        ]);
+        column = column.push(row![
+            text("Wickets to end an innings: "),
+            text_input("Wickets", &self.wickets_input)
+                .on_input(|input| MatchConfigEntryEvent::WicketsChanged(input).as_event()),
+        ]);
+        column = column.push(row![
+            text("Innings per side: "),
+            text_input("Innings", &self.innings_input)
+                .on_input(|input| MatchConfigEntryEvent::InningsChanged(input).as_event()),
+        ]);
+        column = column.push(row![
+            text("Follow-on margin: "),
+            text_input("Runs", &self.follow_on_input)
+                .on_input(|input| MatchConfigEntryEvent::FollowOnChanged(input).as_event()),
+        ]);
+        column = column.push(row![
+            text("Max overs per bowler (blank for unlimited): "),
+            text_input("Overs", &self.max_overs_input)
+                .on_input(|input| MatchConfigEntryEvent::MaxOversChanged(input).as_event()),
+        ]);
+        column = column.push(
+            checkbox("Free hit after a no-ball", self.free_hits)
+                .on_toggle(|toggled| MatchConfigEntryEvent::FreeHitsToggled(toggled).as_event()),
+        );
+
+        if self.match_config().is_some() {
+            column = column.push(
+                button("Confirm format").on_press(MatchConfigEntryEvent::SubmitConfig.as_event()),
+            );
+        }
+
+        column.into()
+    }
+}
+
+impl MatchConfigEntry {
+    pub fn new() -> Self {
+        let mut match_config_entry = MatchConfigEntry {
+            selected_format: None,
+            overs_input: String::new(),
+            balls_input: String::new(),
+            players_input: String::new(),
+            wickets_input: String::new(),
+            innings_input: String::new(),
+            follow_on_input: String::new(),
+            max_overs_input: String::new(),
+            free_hits: false,
+        };
+
+        match_config_entry.set_inputs(&MatchConfig::default());
+        match_config_entry
+    }
+
+    fn set_inputs(&mut self, match_config: &MatchConfig) {
+        self.overs_input = match match_config.overs_per_innings {
+            Some(overs) => overs.to_string(),
+            None => String::new(),
+        };
+        self.balls_input = match_config.balls_per_over.to_string();
+        self.players_input = match_config.players_per_side.to_string();
+        self.wickets_input = match_config.wickets_per_innings.to_string();
+        self.innings_input = match_config.innings_per_side.to_string();
+        self.follow_on_input = match_config.follow_on_margin.to_string();
+        self.max_overs_input = match match_config.max_overs_per_bowler {
+            Some(overs) => overs.to_string(),
+            None => String::new(),
+        };
+        self.free_hits = match_config.free_hits;
+    }
+
+    fn match_config(&self) -> Option<MatchConfig> {
+        let overs_per_innings = match self.overs_input.trim() {
+            "" => None,
+            overs => Some(overs.parse().ok().filter(|overs| *overs > 0)?),
+        };
+        let balls_per_over = self
+            .balls_input
+            .trim()
+            .parse()
+            .ok()
+            .filter(|balls| *balls > 0)?;
+        let players_per_side: usize = self
+            .players_input
+            .trim()
+            .parse()
+            .ok()
+            .filter(|players| *players > 1)?;
+        let wickets_per_innings = self
+            .wickets_input
+            .trim()
+            .parse()
+            .ok()
+            .filter(|wickets| *wickets > 0 && *wickets < players_per_side as u32)?;
+        let innings_per_side = self
+            .innings_input
+            .trim()
+            .parse()
+            .ok()
+            .filter(|innings| *innings == 1 || *innings == 2)?;
+        let follow_on_margin = self.follow_on_input.trim().parse().ok()?;
+        let max_overs_per_bowler = match self.max_overs_input.trim() {
+            "" => None,
+            overs => Some(overs.parse().ok().filter(|overs| *overs > 0)?),
+        };
+
+        Some(
+            MatchConfig::new(
+                overs_per_innings,
+                balls_per_over,
+                players_per_side,
+                wickets_per_innings,
+            )
+            .with_innings(innings_per_side, follow_on_margin)
+            .with_free_hits(self.free_hits)
+            .with_max_overs_per_bowler(max_overs_per_bowler),
+        )
+    }
+}
+
+impl Default for MatchConfigEntry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, AsComponentEvent)]
+pub enum MatchConfigEntryEvent {
+    FormatSelected(usize),
+    OversChanged(String),
+    BallsChanged(String),
+    PlayersChanged(String),
+    WicketsChanged(String),
+    InningsChanged(String),
+    FollowOnChanged(String),
+    MaxOversChanged(String),
+    FreeHitsToggled(bool),
+    SubmitConfig,
+}
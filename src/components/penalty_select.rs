@@ -0,0 +1,127 @@
+use crate::components::runs_button::RunsButton;
+use crate::components::{AsEvent, Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::event::GameEvent;
+use crate::state::game_state::extras::{Penalty, PenaltyReason};
+use crate::state::game_state::{GameState, TeamType};
+use crate::state::Page;
+use iced::widget::{button, column, radio, text};
+use iced::Element;
+use macros::AsComponentEvent;
+use strum::IntoEnumIterator;
+
+pub struct PenaltySelect {
+    selected_team: Option<usize>,
+    selected_reason: Option<usize>,
+    runs_button: RunsButton,
+}
+
+impl Component for PenaltySelect {
+    fn update(
+        &mut self,
+        event: ComponentEvent,
+        mut game_state: GameState,
+    ) -> (GameState, Option<Page>) {
+        let event = match event {
+            ComponentEvent::PenaltySelectEvent(penalty_select_event) => penalty_select_event,
+            ComponentEvent::RunsButtonEvent(runs_button_event) => {
+                self.runs_button.update(runs_button_event);
+                return (game_state, None);
+            }
+            _ => panic!("Penalty select component has been called with an event that is not a penalty select event!")
+        };
+
+        let mut page = None;
+
+        match event {
+            PenaltySelectEvent::TeamSelected(team) => self.selected_team = Some(team),
+            PenaltySelectEvent::ReasonSelected(reason) => self.selected_reason = Some(reason),
+            PenaltySelectEvent::SubmitPenalty => {
+                let team = match self.selected_team {
+                    Some(0) => TeamType::A,
+                    _ => TeamType::B,
+                };
+                let reason = PenaltyReason::iter().collect::<Vec<PenaltyReason>>()[self
+                    .selected_reason
+                    .expect("Reason should be selected when penalty runs are submitted")]
+                .clone();
+
+                page = game_state
+                    .update(GameEvent::PenaltyRuns(Penalty::new(
+                        team,
+                        self.runs_button.runs,
+                        reason,
+                    )))
+                    .or(Some(Page::Scoring));
+            }
+        }
+
+        (game_state, page)
+    }
+
+    fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
+        let mut column = column![
+            text("Award penalty runs to:"),
+            radio(
+                &game_state.team_a.team_name,
+                0,
+                self.selected_team,
+                |selection| PenaltySelectEvent::TeamSelected(selection).as_event(),
+            ),
+            radio(
+                &game_state.team_b.team_name,
+                1,
+                self.selected_team,
+                |selection| PenaltySelectEvent::TeamSelected(selection).as_event(),
+            ),
+            text("Reason:"),
+        ];
+
+        for (i, reason) in PenaltyReason::iter().enumerate() {
+            column = column.push(radio(
+                reason.to_string(),
+                i,
+                self.selected_reason,
+                |selection| PenaltySelectEvent::ReasonSelected(selection).as_event(),
+            ));
+        }
+
+        column = column.push(self.runs_button.view());
+
+        if self.selected_team.is_some() && self.selected_reason.is_some() {
+            column = column.push(
+                button("Award penalty runs").on_press(PenaltySelectEvent::SubmitPenalty.as_event()),
+            );
+        }
+
+        column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+
+        column.into()
+    }
+}
+
+impl PenaltySelect {
+    pub fn new() -> Self {
+        let mut runs_button = RunsButton::new(1);
+        runs_button.runs = 5;
+
+        PenaltySelect {
+            selected_team: None,
+            selected_reason: None,
+            runs_button,
+        }
+    }
+}
+
+impl Default for PenaltySelect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, AsComponentEvent)]
+pub enum PenaltySelectEvent {
+    TeamSelected(usize),
+    ReasonSelected(usize),
+    SubmitPenalty,
+}
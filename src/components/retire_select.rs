@@ -0,0 +1,116 @@
+use crate::components::{AsEvent, Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::event::GameEvent;
+use crate::state::game_state::wickets::{HowOut, Retirement};
+use crate::state::game_state::{GameState, PlayerType};
+use crate::state::Page;
+use iced::widget::{button, column, radio, text};
+use iced::Element;
+use macros::AsComponentEvent;
+use strum::IntoEnumIterator;
+
+pub struct RetireSelect {
+    selected_batter: Option<usize>,
+    selected_how_out: Option<usize>,
+}
+
+impl Component for RetireSelect {
+    fn update(
+        &mut self,
+        event: ComponentEvent,
+        mut game_state: GameState,
+    ) -> (GameState, Option<Page>) {
+        let event = match event {
+            ComponentEvent::RetireSelectEvent(retire_select_event) => retire_select_event,
+            _ => panic!("Retire select component has been called with an event that is not a retire select event!")
+        };
+
+        let mut page = None;
+
+        match event {
+            RetireSelectEvent::BatterSelected(batter) => self.selected_batter = Some(batter),
+            RetireSelectEvent::HowOutSelected(how_out) => self.selected_how_out = Some(how_out),
+            RetireSelectEvent::SubmitRetirement => {
+                let batter = match self.selected_batter {
+                    Some(0) => PlayerType::A,
+                    _ => PlayerType::B,
+                };
+                let how_out = HowOut::iter().collect::<Vec<HowOut>>()[self
+                    .selected_how_out
+                    .expect("How out should be selected when a retirement is submitted")]
+                .clone();
+
+                page = game_state
+                    .update(GameEvent::Retire(Retirement::new(batter, how_out)))
+                    .or(Some(Page::Scoring));
+            }
+        }
+
+        (game_state, page)
+    }
+
+    fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
+        let mut column = column![text("Select retiring batter:")];
+
+        for (i, batter) in [&game_state.batter_a, &game_state.batter_b]
+            .into_iter()
+            .enumerate()
+        {
+            if let Some(batter) = batter {
+                column = column.push(radio(
+                    batter.borrow().to_string(),
+                    i,
+                    self.selected_batter,
+                    |selection| RetireSelectEvent::BatterSelected(selection).as_event(),
+                ));
+            }
+        }
+
+        column = column.push(text("Reason:"));
+
+        for (i, how_out) in HowOut::iter().enumerate() {
+            if !how_out.is_retirement() {
+                continue;
+            }
+
+            column = column.push(radio(
+                how_out.to_string(),
+                i,
+                self.selected_how_out,
+                |selection| RetireSelectEvent::HowOutSelected(selection).as_event(),
+            ));
+        }
+
+        if self.selected_batter.is_some() && self.selected_how_out.is_some() {
+            column = column.push(
+                button("Retire batter").on_press(RetireSelectEvent::SubmitRetirement.as_event()),
+            );
+        }
+
+        column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+
+        column.into()
+    }
+}
+
+impl RetireSelect {
+    pub fn new() -> Self {
+        RetireSelect {
+            selected_batter: None,
+            selected_how_out: None,
+        }
+    }
+}
+
+impl Default for RetireSelect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, AsComponentEvent)]
+pub enum RetireSelectEvent {
+    BatterSelected(usize),
+    HowOutSelected(usize),
+    SubmitRetirement,
+}
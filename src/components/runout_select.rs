@@ -1,17 +1,25 @@
 use crate::components::fielder_select::{FielderSelect, FielderSelectEvent};
+use crate::components::runs_button::{RunsButton, RunsButtonEvent};
 use crate::components::wicket_select::WicketSubcomponentData;
 use crate::components::{AsEvent, ComponentEvent, Subcomponent, SubcomponentEvent};
 use crate::state::event::Event;
-use crate::state::game_state::GameState;
+use crate::state::game_state::wickets::{CreaseEnd, RunOut};
+use crate::state::game_state::{GameState, PlayerType};
 use crate::state::Page;
 use macros::AsSubcomponentEvent;
+use strum::IntoEnumIterator;
 
-use iced::widget::row;
+use iced::widget::{button, checkbox, column, radio, row, text};
 use iced::Element;
 
 pub struct RunoutSelect {
     batter_select: FielderSelect,
     fielder_select: FielderSelect,
+    assist_select: FielderSelect,
+    direct_hit: bool,
+    runs_button: RunsButton,
+    selected_end: Option<usize>,
+    batter_a_order: usize,
 }
 
 impl Subcomponent<WicketSubcomponentData> for RunoutSelect {
@@ -42,21 +50,63 @@ impl Subcomponent<WicketSubcomponentData> for RunoutSelect {
                     GameState::new(),
                 );
             }
+            RunoutSelectEvent::AssistSelectEvent(selection) => {
+                self.assist_select.update(
+                    SubcomponentEvent::FielderSelectEvent(FielderSelectEvent::FielderSelected(
+                        selection,
+                    )),
+                    GameState::new(),
+                );
+            }
+            RunoutSelectEvent::DirectHitToggled(direct_hit) => self.direct_hit = direct_hit,
+            RunoutSelectEvent::RunsChanged(runs_button_event) => {
+                self.runs_button.update(runs_button_event)
+            }
+            RunoutSelectEvent::EndSelected(end_index) => self.selected_end = Some(end_index),
         }
 
         (game_state, None)
     }
 
     fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
-        row![
+        let mut column = column![
+            text("Runs completed:"),
+            row![
+                button("-")
+                    .on_press(RunoutSelectEvent::RunsChanged(RunsButtonEvent::Subtract).as_event()),
+                text(self.runs_button.runs),
+                button("+")
+                    .on_press(RunoutSelectEvent::RunsChanged(RunsButtonEvent::Add).as_event()),
+            ],
+            text("Run out at:"),
+        ];
+
+        for (i, end) in CreaseEnd::iter().enumerate() {
+            column = column.push(radio(end.to_string(), i, self.selected_end, |selection| {
+                RunoutSelectEvent::EndSelected(selection).as_event()
+            }));
+        }
+
+        column = column.push(
+            checkbox("Direct hit", self.direct_hit)
+                .on_toggle(|toggled| RunoutSelectEvent::DirectHitToggled(toggled).as_event()),
+        );
+
+        let mut row = row![
             self.batter_select.view(&game_state),
+            column,
             self.fielder_select.view(&game_state),
-        ]
-        .into()
+        ];
+
+        if !self.direct_hit {
+            row = row.push(self.assist_select.view(game_state));
+        }
+
+        row.into()
     }
 
     fn can_submit(&self) -> bool {
-        true
+        self.selected_end.is_some()
     }
 
     fn get_value(&self) -> Option<WicketSubcomponentData> {
@@ -71,8 +121,28 @@ impl Subcomponent<WicketSubcomponentData> for RunoutSelect {
                 None => None,
             };
 
+            let batter = if batter == self.batter_a_order {
+                PlayerType::A
+            } else {
+                PlayerType::B
+            };
+            let end = CreaseEnd::iter().collect::<Vec<CreaseEnd>>()[self
+                .selected_end
+                .expect("End should be selected for a run out")]
+            .clone();
+            let mut run_out =
+                RunOut::new(self.runs_button.runs, batter, end).with_direct_hit(self.direct_hit);
+
+            if !self.direct_hit {
+                if let Some(WicketSubcomponentData::AssistSubcomponent(assist)) =
+                    self.assist_select.get_value()
+                {
+                    run_out = run_out.with_assist(assist);
+                }
+            }
+
             return Some(WicketSubcomponentData::RunoutSubcomponent((
-                batter, fielder,
+                run_out, fielder,
             )));
         }
 
@@ -82,11 +152,13 @@ impl Subcomponent<WicketSubcomponentData> for RunoutSelect {
 
 impl RunoutSelect {
     pub fn new(game_state: &GameState) -> Self {
+        let batter_a_order = game_state.batter_a.as_ref().unwrap().borrow().order;
         let batters = vec![
             game_state.batter_a.as_ref().unwrap().clone(),
             game_state.batter_b.as_ref().unwrap().clone(),
         ];
         let fielders = game_state.bowling_team().players.clone();
+        let assists = fielders.clone();
 
         let mut component = Self {
             batter_select: FielderSelect::new_with_selection_fn(
@@ -98,9 +170,21 @@ impl RunoutSelect {
                 fielders,
                 Box::new(|selection| RunoutSelectEvent::FielderSelectEvent(selection).as_event()),
             ),
+            assist_select: FielderSelect::new_with_selection_fn(
+                assists,
+                Box::new(|selection| RunoutSelectEvent::AssistSelectEvent(selection).as_event()),
+            )
+            .with_value_fn(WicketSubcomponentData::AssistSubcomponent)
+            .with_message(String::from("Select assisting fielder:")),
+            direct_hit: true,
+            runs_button: RunsButton::new(0),
+            selected_end: None,
+            batter_a_order,
         };
 
-        component.batter_select.select_player(0);
+        // the striker is the most likely batter to be run out
+        let on_strike_batter = game_state.on_strike_batter().unwrap().borrow().order;
+        component.batter_select.select_player(on_strike_batter);
         component
     }
 }
@@ -109,4 +193,8 @@ impl RunoutSelect {
 pub enum RunoutSelectEvent {
     BatterSelectEvent(usize),
     FielderSelectEvent(usize),
+    AssistSelectEvent(usize),
+    DirectHitToggled(bool),
+    RunsChanged(RunsButtonEvent),
+    EndSelected(usize),
 }
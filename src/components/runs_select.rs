@@ -0,0 +1,140 @@
+use crate::components::runs_button::{RunsButton, RunsButtonEvent};
+use crate::components::{AsEvent, Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::event::GameEvent;
+use crate::state::game_state::runs::{Boundary, Runs};
+use crate::state::game_state::GameState;
+use crate::state::Page;
+use iced::widget::{button, column, radio, row, text, Row};
+use iced::Element;
+use macros::AsComponentEvent;
+use strum::IntoEnumIterator;
+
+pub struct RunsSelect {
+    runs_button: RunsButton,
+    overthrows_button: RunsButton,
+    short_runs_button: RunsButton,
+    selected_boundary: Option<usize>,
+}
+
+impl Component for RunsSelect {
+    fn update(
+        &mut self,
+        event: ComponentEvent,
+        mut game_state: GameState,
+    ) -> (GameState, Option<Page>) {
+        let event = match event {
+            ComponentEvent::RunsSelectEvent(runs_select_event) => runs_select_event,
+            _ => panic!("Runs select component has been called with an event that is not a runs select event!")
+        };
+
+        let mut page = None;
+
+        match event {
+            RunsSelectEvent::RunsChanged(runs_button_event) => {
+                self.runs_button.update(runs_button_event)
+            }
+            RunsSelectEvent::OverthrowsChanged(runs_button_event) => {
+                self.overthrows_button.update(runs_button_event)
+            }
+            RunsSelectEvent::ShortRunsChanged(runs_button_event) => {
+                self.short_runs_button.update(runs_button_event)
+            }
+            RunsSelectEvent::BoundarySelected(boundary_index) => {
+                self.selected_boundary = Some(boundary_index)
+            }
+            RunsSelectEvent::SubmitRuns => {
+                page = game_state
+                    .update(GameEvent::Runs(self.runs()))
+                    .or(Some(Page::Scoring));
+            }
+        }
+
+        (game_state, page)
+    }
+
+    fn view<'a>(&'a self, _: &'a GameState) -> Element<'a, Event> {
+        let mut column = column![
+            text("Runs run:"),
+            Self::runs_row(&self.runs_button, RunsSelectEvent::RunsChanged),
+            text("Overthrows run:"),
+            Self::runs_row(&self.overthrows_button, RunsSelectEvent::OverthrowsChanged),
+            text("Runs called short:"),
+            Self::runs_row(&self.short_runs_button, RunsSelectEvent::ShortRunsChanged),
+            text("Boundary:"),
+            radio("None", 0, self.selected_boundary, |selection| {
+                RunsSelectEvent::BoundarySelected(selection).as_event()
+            }),
+        ];
+
+        for (i, boundary) in Boundary::iter().enumerate() {
+            column = column.push(radio(
+                boundary.to_string(),
+                i + 1,
+                self.selected_boundary,
+                |selection| RunsSelectEvent::BoundarySelected(selection).as_event(),
+            ));
+        }
+
+        let runs = self.runs();
+
+        column = column.push(text(format!(
+            "{runs}: {total} to the total",
+            total = runs.total_runs()
+        )));
+        column = column.push(button("Submit").on_press(RunsSelectEvent::SubmitRuns.as_event()));
+        column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+
+        column.into()
+    }
+}
+
+impl RunsSelect {
+    pub fn new() -> Self {
+        RunsSelect {
+            runs_button: RunsButton::new(0),
+            overthrows_button: RunsButton::new(0),
+            short_runs_button: RunsButton::new(0),
+            selected_boundary: Some(0),
+        }
+    }
+
+    fn runs(&self) -> Runs {
+        let mut runs = Runs::new(self.runs_button.runs)
+            .with_overthrows(self.overthrows_button.runs)
+            .with_short_runs(self.short_runs_button.runs);
+
+        if let Some(boundary_index) = self.selected_boundary.filter(|index| *index > 0) {
+            let boundary = Boundary::iter().collect::<Vec<Boundary>>()[boundary_index - 1].clone();
+            runs = runs.with_boundary(boundary);
+        }
+
+        runs
+    }
+
+    fn runs_row<'a>(
+        runs_button: &RunsButton,
+        event: fn(RunsButtonEvent) -> RunsSelectEvent,
+    ) -> Row<'a, Event> {
+        row![
+            button("-").on_press(event(RunsButtonEvent::Subtract).as_event()),
+            text(runs_button.runs),
+            button("+").on_press(event(RunsButtonEvent::Add).as_event()),
+        ]
+    }
+}
+
+impl Default for RunsSelect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, AsComponentEvent)]
+pub enum RunsSelectEvent {
+    RunsChanged(RunsButtonEvent),
+    OverthrowsChanged(RunsButtonEvent),
+    ShortRunsChanged(RunsButtonEvent),
+    BoundarySelected(usize),
+    SubmitRuns,
+}
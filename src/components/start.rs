@@ -22,7 +22,7 @@ impl Component for Start {
         let page;
 
         match event {
-            StartEvent::NewGame => page = Some(Page::TeamEntry),
+            StartEvent::NewGame => page = Some(Page::MatchConfig),
         }
 
         (game_state, page)
@@ -0,0 +1,185 @@
+use crate::components::{AsEvent, Component, ComponentEvent};
+use crate::state::event::Event;
+use crate::state::game_state::event::GameEvent;
+use crate::state::game_state::substitutions::{Substitution, SubstitutionKind};
+use crate::state::game_state::{GameState, TeamType};
+use crate::state::Page;
+use iced::widget::{button, column, radio, text};
+use iced::Element;
+use macros::AsComponentEvent;
+use strum::IntoEnumIterator;
+
+pub struct SubstitutionSelect {
+    selected_team: Option<usize>,
+    selected_kind: Option<usize>,
+    selected_player_off: Option<usize>,
+    selected_player_on: Option<usize>,
+}
+
+impl Component for SubstitutionSelect {
+    fn update(
+        &mut self,
+        event: ComponentEvent,
+        mut game_state: GameState,
+    ) -> (GameState, Option<Page>) {
+        let event = match event {
+            ComponentEvent::SubstitutionSelectEvent(substitution_select_event) => substitution_select_event,
+            _ => panic!("Substitution select component has been called with an event that is not a substitution select event!")
+        };
+
+        let mut page = None;
+
+        match event {
+            SubstitutionSelectEvent::TeamSelected(team) => {
+                self.selected_team = Some(team);
+                self.selected_player_off = None;
+                self.selected_player_on = None;
+            }
+            SubstitutionSelectEvent::KindSelected(kind) => self.selected_kind = Some(kind),
+            SubstitutionSelectEvent::PlayerOffSelected(order) => {
+                self.selected_player_off = Some(order)
+            }
+            SubstitutionSelectEvent::PlayerOnSelected(order) => {
+                self.selected_player_on = Some(order)
+            }
+            SubstitutionSelectEvent::SubmitSubstitution => {
+                let substitution = Substitution::new(
+                    self.team().expect("Selected team should exist"),
+                    self.kind().expect("Selected kind should exist"),
+                    self.selected_player_off
+                        .expect("Selected player off should exist"),
+                    self.selected_player_on
+                        .expect("Selected player on should exist"),
+                );
+
+                game_state.update(GameEvent::Substitute(substitution));
+                page = Some(Page::Scoring);
+            }
+        }
+
+        (game_state, page)
+    }
+
+    fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
+        let mut column = column![
+            text("Team:"),
+            radio(
+                game_state.team(&TeamType::A).team_name.clone(),
+                0,
+                self.selected_team,
+                |selection| SubstitutionSelectEvent::TeamSelected(selection).as_event()
+            ),
+            radio(
+                game_state.team(&TeamType::B).team_name.clone(),
+                1,
+                self.selected_team,
+                |selection| SubstitutionSelectEvent::TeamSelected(selection).as_event()
+            ),
+            text("Substitution:"),
+        ];
+
+        for (i, kind) in SubstitutionKind::iter().enumerate() {
+            column = column.push(radio(
+                kind.to_string(),
+                i,
+                self.selected_kind,
+                |selection| SubstitutionSelectEvent::KindSelected(selection).as_event(),
+            ));
+        }
+
+        if let Some(team_type) = self.team() {
+            let team = game_state.team(&team_type);
+
+            column = column.push(text("Player off:"));
+            for player in team.playing_players() {
+                if game_state.is_batting_or_bowling(&player) {
+                    continue;
+                }
+
+                let player = player.borrow();
+
+                column = column.push(radio(
+                    player.to_string(),
+                    player.order,
+                    self.selected_player_off,
+                    |selection| SubstitutionSelectEvent::PlayerOffSelected(selection).as_event(),
+                ));
+            }
+
+            let reserves = team.reserves();
+
+            if reserves.is_empty() {
+                column = column.push(text("No reserves were named in this squad"));
+            } else {
+                column = column.push(text("Player on:"));
+            }
+
+            for player in reserves {
+                let player = player.borrow();
+
+                if team.was_replaced(player.order) {
+                    continue;
+                }
+
+                column = column.push(radio(
+                    player.to_string(),
+                    player.order,
+                    self.selected_player_on,
+                    |selection| SubstitutionSelectEvent::PlayerOnSelected(selection).as_event(),
+                ));
+            }
+        }
+
+        if self.kind().is_some()
+            && self.selected_player_off.is_some()
+            && self.selected_player_on.is_some()
+        {
+            column = column.push(
+                button("Make substitution")
+                    .on_press(SubstitutionSelectEvent::SubmitSubstitution.as_event()),
+            );
+        }
+
+        column = column.push(button("Cancel").on_press(Event::ChangePage(Page::Scoring)));
+
+        column.into()
+    }
+}
+
+impl SubstitutionSelect {
+    pub fn new() -> Self {
+        SubstitutionSelect {
+            selected_team: None,
+            selected_kind: Some(0),
+            selected_player_off: None,
+            selected_player_on: None,
+        }
+    }
+
+    fn team(&self) -> Option<TeamType> {
+        self.selected_team.map(|team| match team {
+            0 => TeamType::A,
+            _ => TeamType::B,
+        })
+    }
+
+    fn kind(&self) -> Option<SubstitutionKind> {
+        self.selected_kind
+            .and_then(|kind| SubstitutionKind::iter().nth(kind))
+    }
+}
+
+impl Default for SubstitutionSelect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, AsComponentEvent)]
+pub enum SubstitutionSelectEvent {
+    TeamSelected(usize),
+    KindSelected(usize),
+    PlayerOffSelected(usize),
+    PlayerOnSelected(usize),
+    SubmitSubstitution,
+}
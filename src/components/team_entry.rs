@@ -5,7 +5,7 @@ use crate::state::game_state::TeamType;
 use crate::state::event::Event;
 use crate::state::game_state::GameState;
 use crate::state::Page;
-use iced::widget::{button, column, row, text_input};
+use iced::widget::{button, checkbox, column, row, text_input};
 use iced::Element;
 use macros::AsComponentEvent;
 
@@ -13,6 +13,8 @@ pub struct TeamEntry {
     first_name_input: String,
     last_name_input: String,
     team_name_input: String,
+    captain: bool,
+    wicket_keeper: bool,
     order: usize,
 }
 
@@ -33,10 +35,14 @@ impl Component for TeamEntry {
             TeamEntryEvent::FirstNameChanged(first_name) => self.first_name_input = first_name,
             TeamEntryEvent::LastNameChanged(last_name) => self.last_name_input = last_name,
             TeamEntryEvent::SubmitName => {
-                let player = Player::new(&self.first_name_input, &self.last_name_input, self.order);
+                let player = Player::new(&self.first_name_input, &self.last_name_input, self.order)
+                    .with_roles(self.captain, self.wicket_keeper)
+                    .with_reserve(self.order >= game_state.match_config.players_per_side);
                 game_state.update(GameEvent::AddPlayer(player));
                 self.first_name_input.clear();
                 self.last_name_input.clear();
+                self.captain = false;
+                self.wicket_keeper = false;
                 self.order += 1;
             }
             TeamEntryEvent::SubmitTeam => {
@@ -49,6 +55,10 @@ impl Component for TeamEntry {
                 self.team_name_input.clear();
             }
             TeamEntryEvent::TeamNameChanged(team_name) => self.team_name_input = team_name,
+            TeamEntryEvent::CaptainToggled(captain) => self.captain = captain,
+            TeamEntryEvent::WicketKeeperToggled(wicket_keeper) => {
+                self.wicket_keeper = wicket_keeper
+            }
         }
 
         (game_state, page)
@@ -61,12 +71,16 @@ impl Component for TeamEntry {
                     .on_input(|input| { TeamEntryEvent::FirstNameChanged(input).as_event() }),
                 text_input("Last Name", &self.last_name_input)
                     .on_input(|input| { TeamEntryEvent::LastNameChanged(input).as_event() }),
+                checkbox("Captain", self.captain)
+                    .on_toggle(|toggled| TeamEntryEvent::CaptainToggled(toggled).as_event()),
+                checkbox("Wicketkeeper", self.wicket_keeper)
+                    .on_toggle(|toggled| TeamEntryEvent::WicketKeeperToggled(toggled).as_event()),
                 button("Submit").on_press(TeamEntryEvent::SubmitName.as_event()),
             ],
             game_state.player_column(),
         ];
 
-        if game_state.team_length() >= 11 {
+        if game_state.team_length() >= game_state.match_config.players_per_side {
             column = column.push(
                 text_input("Team Name", &self.team_name_input)
                     .on_input(|input| TeamEntryEvent::TeamNameChanged(input).as_event()),
@@ -85,6 +99,8 @@ impl TeamEntry {
             first_name_input: String::new(),
             last_name_input: String::new(),
             team_name_input: String::new(),
+            captain: false,
+            wicket_keeper: false,
             order: 0,
         }
     }
@@ -97,4 +113,6 @@ pub enum TeamEntryEvent {
     TeamNameChanged(String),
     SubmitName,
     SubmitTeam,
+    CaptainToggled(bool),
+    WicketKeeperToggled(bool),
 }
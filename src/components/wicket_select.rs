@@ -2,8 +2,8 @@ use crate::components::fielder_select::FielderSelect;
 use crate::components::runout_select::RunoutSelect;
 use crate::components::{AsEvent, Component, ComponentEvent, Subcomponent};
 use crate::state::game_state::event::GameEvent;
-use crate::state::game_state::wickets::{HowOut, WicketEvent};
-use crate::state::game_state::PlayerType;
+use crate::state::game_state::extras::{ByeType, Extra, ExtraType};
+use crate::state::game_state::wickets::{HowOut, RunOut, WicketEvent};
 use crate::state::event::Event;
 use crate::state::game_state::GameState;
 use crate::state::Page;
@@ -14,11 +14,14 @@ use strum::IntoEnumIterator;
 
 pub enum WicketSubcomponentData {
     CaughtSubcomponent(usize),
-    RunoutSubcomponent((usize, Option<usize>)),
+    AssistSubcomponent(usize),
+    RunoutSubcomponent((RunOut, Option<usize>)),
 }
 
 pub struct WicketSelect {
     selected_how_out: Option<usize>,
+    selected_extra: Option<usize>,
+    selected_byes: Option<usize>,
     subcomponent: Option<Box<dyn Subcomponent<WicketSubcomponentData>>>,
 }
 
@@ -70,43 +73,79 @@ impl Component for WicketSelect {
                     .selected_how_out
                     .expect("How out should be selected when a wicket is submitted")]
                 .clone();
-                let bowler = Some(game_state.bowler.as_ref().unwrap().borrow().order);
+                let bowler = Some(game_state.bowler.as_ref().unwrap().borrow().order)
+                    .filter(|_| how_out.rules().credits_bowler);
                 let data = match &self.subcomponent {
                     Some(subcomponent) => subcomponent.get_value(),
                     None => None,
                 };
 
                 let fielder: Option<usize>;
+                let mut run_out = None;
 
                 match data {
                     Some(data) => match data {
                         WicketSubcomponentData::CaughtSubcomponent(fielder_num) => {
                             fielder = Some(fielder_num)
                         }
-                        WicketSubcomponentData::RunoutSubcomponent((
-                            selected_batter,
-                            fielder_num,
-                        )) => {
-                            let batter_num = match game_state.on_strike_batter {
-                                PlayerType::A => 0,
-                                PlayerType::B => 1,
-                            };
-
-                            if batter_num != selected_batter {
-                                game_state.change_strike();
-                            }
-
+                        WicketSubcomponentData::RunoutSubcomponent((run_out_data, fielder_num)) => {
+                            run_out = Some(run_out_data);
                             fielder = fielder_num;
                         }
+                        WicketSubcomponentData::AssistSubcomponent(_) => {
+                            panic!("an assist is only selected as part of a run out")
+                        }
                     },
                     None => fielder = None,
                 }
 
-                game_state.update(GameEvent::Wicket(WicketEvent::new(
-                    how_out, bowler, fielder,
-                )));
-                page = Some(Page::SelectBatter);
+                // stumpings are always made by the wicketkeeper
+                let fielder = match how_out {
+                    HowOut::Stumped => game_state
+                        .bowling_team()
+                        .wicket_keeper()
+                        .map(|keeper| keeper.borrow().order),
+                    _ => fielder,
+                };
+
+                let mut wicket_event = WicketEvent::new(how_out, bowler, fielder);
+
+                let runs = run_out.as_ref().map_or(0, |run_out| run_out.runs_completed);
+                let extra = match (self.selected_extra_type(), self.selected_bye_type()) {
+                    (Some(extra_type), Some(byes)) => {
+                        Some(Extra::new(runs, extra_type).with_byes(byes))
+                    }
+                    (Some(extra_type), None) => Some(Extra::new(runs, extra_type)),
+                    (None, Some(ByeType::Bye)) => Some(Extra::new(runs, ExtraType::Bye)),
+                    (None, Some(ByeType::LegBye)) => Some(Extra::new(runs, ExtraType::LegBye)),
+                    (None, None) => None,
+                };
+
+                if let Some(extra) = extra {
+                    wicket_event = wicket_event.with_extra(extra);
+                }
+
+                if let Some(run_out) = run_out {
+                    wicket_event = wicket_event.with_run_out(run_out);
+                }
+
+                page = game_state.update(GameEvent::Wicket(wicket_event));
             }
+            WicketSelectEvent::ExtraSelected(extra_index) => {
+                self.selected_extra = Some(extra_index);
+
+                let how_outs = HowOut::iter().collect::<Vec<HowOut>>();
+
+                if let (Some(how_out_index), Some(extra_type)) =
+                    (self.selected_how_out, self.selected_extra_type())
+                {
+                    if !how_outs[how_out_index].is_allowed_on_extra(&extra_type) {
+                        self.selected_how_out = None;
+                        self.subcomponent = None;
+                    }
+                }
+            }
+            WicketSelectEvent::ByesSelected(byes_index) => self.selected_byes = Some(byes_index),
             WicketSelectEvent::SubcomponentEvent => (),
         }
 
@@ -116,11 +155,51 @@ impl Component for WicketSelect {
     fn view<'a>(&'a self, game_state: &'a GameState) -> Element<'a, Event> {
         let mut column = column![text("Select how out:")];
 
+        if game_state.free_hit {
+            column = column.push(text("FREE HIT"));
+        }
+
+        column = column.push(text("Delivery:"));
+        column = column.push(radio("Legal ball", 0, self.selected_extra, |selection| {
+            WicketSelectEvent::ExtraSelected(selection).as_event()
+        }));
+
+        for (i, extra_type) in ExtraType::iter().enumerate() {
+            match extra_type {
+                ExtraType::Wide | ExtraType::NoBall => (),
+                _ => continue,
+            }
+            column = column.push(radio(
+                format!("Off a {}", extra_type.to_string().to_lowercase()),
+                i + 1,
+                self.selected_extra,
+                |selection| WicketSelectEvent::ExtraSelected(selection).as_event(),
+            ));
+        }
+
+        let extra_type = self.selected_extra_type();
+
         for (i, how_out) in HowOut::iter().enumerate() {
+            // retirements are recorded from the scoring page
             match how_out {
-                HowOut::DidNotBat | HowOut::NotOut => continue,
+                HowOut::DidNotBat
+                | HowOut::NotOut
+                | HowOut::RetiredHurt
+                | HowOut::RetiredNotOut => continue,
                 _ => (),
             }
+            if game_state.free_hit && !how_out.is_allowed_on_free_hit() {
+                continue;
+            }
+            // a batter can only be stumped by the wicketkeeper
+            if how_out == HowOut::Stumped && game_state.bowling_team().wicket_keeper().is_none() {
+                continue;
+            }
+            if let Some(extra_type) = &extra_type {
+                if !how_out.is_allowed_on_extra(extra_type) {
+                    continue;
+                }
+            }
             column = column.push(radio(
                 how_out.to_string(),
                 i,
@@ -129,6 +208,22 @@ impl Component for WicketSelect {
             ));
         }
 
+        if self.is_run_out() {
+            column = column.push(text("Runs completed were:"));
+            column = column.push(radio("Off the bat", 0, self.selected_byes, |selection| {
+                WicketSelectEvent::ByesSelected(selection).as_event()
+            }));
+
+            for (i, bye_type) in ByeType::iter().enumerate() {
+                column = column.push(radio(
+                    format!("{bye_type}s"),
+                    i + 1,
+                    self.selected_byes,
+                    |selection| WicketSelectEvent::ByesSelected(selection).as_event(),
+                ));
+            }
+        }
+
         if self.selected_how_out.is_some()
             && (self.subcomponent.is_none() || self.subcomponent.as_ref().unwrap().can_submit())
         {
@@ -151,14 +246,45 @@ impl WicketSelect {
     pub fn new() -> Self {
         Self {
             selected_how_out: None,
+            selected_extra: Some(0),
+            selected_byes: Some(0),
             subcomponent: None,
         }
     }
+
+    fn is_run_out(&self) -> bool {
+        self.selected_how_out
+            .is_some_and(|how_out_index| HowOut::iter().nth(how_out_index) == Some(HowOut::RunOut))
+    }
+
+    // runs completed before a run out can be byes or leg byes rather than
+    // runs off the bat
+    fn selected_bye_type(&self) -> Option<ByeType> {
+        if !self.is_run_out() {
+            return None;
+        }
+
+        match self.selected_byes? {
+            0 => None,
+            byes_index => ByeType::iter().nth(byes_index - 1),
+        }
+    }
+
+    fn selected_extra_type(&self) -> Option<ExtraType> {
+        match self.selected_extra? {
+            0 => None,
+            extra_index => {
+                Some(ExtraType::iter().collect::<Vec<ExtraType>>()[extra_index - 1].clone())
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, AsComponentEvent)]
 pub enum WicketSelectEvent {
     HowOutSelected(usize),
+    ExtraSelected(usize),
+    ByesSelected(usize),
     SubmitWicket,
     SubcomponentEvent,
 }
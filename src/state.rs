@@ -5,17 +5,27 @@ use std::fs;
 
 use bincode;
 use event::Event;
-use game_state::event::GameEvent;
+use game_state::event::{EventCorrection, GameEvent};
 use game_state::GameState;
+use iced::widget::{column, text};
 use iced::Element;
 use rfd::FileDialog;
 use serde_json;
+use serde_json::{json, Value};
 
 use crate::components::batter_select::BatterSelect;
 use crate::components::bowler_select::BowlerSelect;
+use crate::components::correction_select::CorrectionSelect;
+use crate::components::crease_select::CreaseSelect;
 use crate::components::extra_select::ExtraSelect;
 use crate::components::innings_select::InningsSelect;
+use crate::components::keeper_select::KeeperSelect;
+use crate::components::match_config_entry::MatchConfigEntry;
+use crate::components::penalty_select::PenaltySelect;
+use crate::components::retire_select::RetireSelect;
+use crate::components::runs_select::RunsSelect;
 use crate::components::start::Start;
+use crate::components::substitution_select::SubstitutionSelect;
 use crate::components::team_entry::TeamEntry;
 use crate::components::wicket_select::WicketSelect;
 use crate::components::Component;
@@ -24,6 +34,7 @@ pub struct State {
     game_state: GameState,
     page: Page,
     component: Box<dyn Component>,
+    load_error: Option<String>,
 }
 
 impl State {
@@ -43,14 +54,23 @@ impl State {
                 self.game_state = game_state;
             }
             Event::GameEvent(game_event) => page = self.game_state.update(game_event),
+            // leaving an edit without submitting it keeps the log as it was
+            Event::ChangePage(Page::Scoring) if self.game_state.correction.is_some() => {
+                self.game_state.cancel_correction();
+                page = self.game_state.next_page().or(Some(Page::Scoring));
+            }
             Event::ChangePage(page) => self.set_page(page),
-            Event::LoadGame => self.load_game(),
+            Event::LoadGame => {
+                if let Err(error) = self.load_game() {
+                    self.load_error = Some(error);
+                }
+            }
             Event::SaveGame => self.save_game(),
             Event::Undo => {
                 let mut end_index = self.game_state.events.len() - 1;
 
                 while end_index != 0 {
-                    if self.game_state.events[end_index].is_ball() {
+                    if self.game_state.events[end_index].is_undo_point() {
                         break;
                     }
                     if self.game_state.events[end_index].is_setup_event() {
@@ -62,11 +82,23 @@ impl State {
                 }
 
                 self.game_state =
-                    GameState::from_events(self.game_state.events[..end_index].to_vec())
+                    GameState::from_events(self.game_state.events[..end_index].to_vec());
+
+                // the rebuilt state may be waiting on a batter or bowler
+                page = self.game_state.next_page().or(Some(Page::Scoring));
+            }
+            Event::EditDelivery(index) | Event::InsertDelivery(index) => {
+                let insert = matches!(event, Event::InsertDelivery(_));
+                let correction =
+                    EventCorrection::new(self.game_state.events.clone(), index, insert);
+
+                self.game_state = GameState::for_correction(correction);
+                page = Some(Page::EditDelivery);
             }
             Event::DeleteEvent(index) => {
                 self.game_state.events.remove(index);
                 self.game_state = GameState::from_events(self.game_state.events.clone());
+                page = self.game_state.next_page().or(Some(Page::Scoring));
             }
         }
 
@@ -76,34 +108,62 @@ impl State {
     }
 
     pub fn view(&self) -> Element<Event> {
-        match self.page {
+        let view = match self.page {
             Page::Scoring => self.game_state.view(),
+            Page::Result => self.game_state.result_view(),
+            Page::Scorecard => self.game_state.scorecard_view(),
             _ => self.component.view(&self.game_state),
+        };
+
+        match &self.load_error {
+            Some(error) => column![text(error), view].into(),
+            None => view,
         }
     }
 }
 
 impl State {
-    fn load_game(&mut self) {
-        let file_path = FileDialog::new()
+    fn load_game(&mut self) -> Result<(), String> {
+        let Some(file_path) = FileDialog::new()
             .add_filter("scricket", &["scr"])
             .add_filter("json", &["json"])
             .pick_file()
-            .expect("invalid path");
+        else {
+            return Ok(());
+        };
 
-        let extension = file_path.extension().unwrap().to_str().unwrap();
-        let data = fs::read(&file_path).expect("file reading error");
+        let extension = file_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+        let data = fs::read(&file_path).map_err(|error| format!("Error reading file: {error}"))?;
+        let load_error = |error: String| format!("Error loading {}: {error}", file_path.display());
 
         let deserialized: Vec<GameEvent> = if extension == "scr" {
-            bincode::deserialize(data.as_slice()).expect("error deserialising")
+            // unlike json, bincode doesn't record field names, so saves from
+            // before the event format changed can't be read or upgraded
+            bincode::deserialize(data.as_slice()).map_err(|error| {
+                load_error(format!(
+                    "{error}. .scr files saved by older versions of scricket are not supported"
+                ))
+            })?
         } else if extension == "json" {
-            serde_json::from_slice(data.as_slice()).expect("error deserialising")
+            let events: Vec<Value> = serde_json::from_slice(data.as_slice())
+                .map_err(|error| load_error(error.to_string()))?;
+
+            upgrade_saved_events(events)
+                .into_iter()
+                .map(serde_json::from_value)
+                .collect::<Result<_, _>>()
+                .map_err(|error| load_error(error.to_string()))?
         } else {
-            panic!("{} is not a valid extension", extension)
+            return Err(format!("{} is not a valid extension", extension));
         };
 
-        self.set_page(Page::Scoring);
         self.game_state = GameState::from_events(deserialized);
+        self.set_page(self.game_state.next_page().unwrap_or(Page::Scoring));
+
+        Ok(())
     }
 
     fn save_game(&self) {
@@ -130,14 +190,25 @@ impl State {
     }
 
     fn set_page(&mut self, page: Page) {
+        self.load_error = None;
+
         match page {
             Page::Start => self.component = Box::new(Start::new()),
             Page::SelectBatter => self.component = Box::new(BatterSelect::new()),
             Page::SelectBowler => self.component = Box::new(BowlerSelect::new(&self.game_state)),
+            Page::ReplaceBowler => self.component = Box::new(BowlerSelect::replacement()),
+            Page::SelectSubstitution => self.component = Box::new(SubstitutionSelect::new()),
+            Page::CorrectBatter => self.component = Box::new(CreaseSelect::new()),
+            Page::EditDelivery => self.component = Box::new(CorrectionSelect::new()),
             Page::TeamEntry => self.component = Box::new(TeamEntry::new()),
             Page::SelectWicket => self.component = Box::new(WicketSelect::new()),
             Page::SelectExtra => self.component = Box::new(ExtraSelect::new()),
             Page::SelectInnings => self.component = Box::new(InningsSelect::new()),
+            Page::MatchConfig => self.component = Box::new(MatchConfigEntry::new()),
+            Page::SelectPenalty => self.component = Box::new(PenaltySelect::new()),
+            Page::SelectRetirement => self.component = Box::new(RetireSelect::new()),
+            Page::SelectRuns => self.component = Box::new(RunsSelect::new()),
+            Page::SelectKeeper => self.component = Box::new(KeeperSelect::new(&self.game_state)),
             _ => (),
         }
 
@@ -151,10 +222,39 @@ impl Default for State {
             game_state: GameState::new(),
             page: Page::Start,
             component: Box::new(Start::new()),
+            load_error: None,
         }
     }
 }
 
+// json games saved by earlier versions recorded the end of an innings without a
+// summary, and penalty runs as an extra for the batting side
+fn upgrade_saved_events(events: Vec<Value>) -> Vec<Value> {
+    let mut batting_team = json!("A");
+
+    events
+        .into_iter()
+        .filter_map(|event| {
+            if let Some(team) = event.get("StartInnings") {
+                batting_team = team.clone();
+            }
+
+            match event.pointer("/Extra/extra_type") {
+                // the end of the innings is worked out again when the game is loaded
+                _ if event == "EndInnings" => None,
+                Some(extra_type) if extra_type == "PenaltyRuns" => Some(json!({
+                    "PenaltyRuns": {
+                        "team": batting_team,
+                        "runs": event["Extra"]["runs"],
+                        "reason": "Other",
+                    }
+                })),
+                _ => Some(event),
+            }
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub enum Page {
     Start,
@@ -165,4 +265,57 @@ pub enum Page {
     SelectWicket,
     SelectExtra,
     SelectInnings,
+    MatchConfig,
+    Result,
+    Scorecard,
+    SelectPenalty,
+    SelectRetirement,
+    SelectKeeper,
+    SelectRuns,
+    ReplaceBowler,
+    SelectSubstitution,
+    CorrectBatter,
+    EditDelivery,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_state::TeamType;
+
+    fn load_json(events: Value) -> Vec<GameEvent> {
+        let events = serde_json::from_value(events).unwrap();
+
+        upgrade_saved_events(events)
+            .into_iter()
+            .map(|event| serde_json::from_value(event).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn legacy_json_runs_load_as_runs_run() {
+        let events = load_json(json!([{ "Runs": 4 }]));
+
+        let [GameEvent::Runs(runs)] = events.as_slice() else {
+            panic!("expected a single runs event, got {events:?}");
+        };
+        assert_eq!(runs.total_runs(), 4);
+        assert!(!runs.is_four());
+    }
+
+    #[test]
+    fn legacy_json_penalty_extras_go_to_the_batting_side() {
+        let events = load_json(json!([
+            { "StartInnings": "B" },
+            { "Extra": { "runs": 5, "extra_type": "PenaltyRuns" } },
+            "EndInnings",
+        ]));
+
+        let [GameEvent::StartInnings(_), GameEvent::PenaltyRuns(penalty)] = events.as_slice()
+        else {
+            panic!("expected the innings start and a penalty, got {events:?}");
+        };
+        assert_eq!(penalty.team, TeamType::B);
+        assert_eq!(penalty.runs, 5);
+    }
 }
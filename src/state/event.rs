@@ -10,4 +10,6 @@ pub enum Event {
     ChangePage(Page),
     Undo,
     DeleteEvent(usize),
+    EditDelivery(usize),
+    InsertDelivery(usize),
 }
@@ -1,24 +1,40 @@
 pub mod event;
 pub mod extras;
+pub mod fielding;
+pub mod innings;
+pub mod match_config;
+pub mod match_result;
 pub mod overs;
+pub mod partnerships;
+pub mod runs;
+mod scorecard;
+pub mod substitutions;
 mod summary;
 pub mod team;
 pub mod wickets;
 
 use crate::state::game_state::summary::Summary;
 use crate::state::{Event, Page};
-use event::{GameEvent, GameEventHistory};
-use extras::ExtraType;
+use event::{EventCorrection, GameEvent, GameEventHistory};
+use extras::{Extra, ExtraType, Penalty};
+use innings::{Innings, InningsStatus};
 use iced::widget::{button, column, row, scrollable, text, Column, Row};
 use iced::Element;
+use match_config::MatchConfig;
+use match_result::MatchResult;
+use overs::Overs;
+use partnerships::{FallOfWicket, Partnership};
+use runs::{Boundary, Runs};
+use substitutions::{Substitution, SubstitutionRecord};
 use std::cell::RefCell;
 use std::rc::Rc;
-pub use team::player::{Player, PlayerType};
+pub use team::player::{BatterCorrection, Player, PlayerType};
 pub use team::{Team, TeamType};
-use wickets::{HowOut, WicketDetails, WicketEvent};
+use wickets::{CreaseEnd, HowOut, Retirement, WicketDetails, WicketEvent};
 
 #[derive(Clone)]
 pub struct GameState {
+    pub match_config: MatchConfig,
     pub team_a: Team,
     pub team_b: Team,
     pub batting_team: TeamType,
@@ -30,11 +46,38 @@ pub struct GameState {
     pub bowler: Option<Rc<RefCell<Player>>>,
     last_bowler: Option<usize>,
     pub last_last_bowler: Option<usize>,
+    // bowlers taken off during the current over, and during the last over
+    replaced_bowlers: Vec<usize>,
+    last_replaced_bowlers: Vec<usize>,
+    pub innings_in_progress: bool,
+    pub follow_on: bool,
+    pub free_hit: bool,
+    // runs the current bowler had conceded when their over started
+    over_start_runs_conceded: u32,
+    pub result: Option<MatchResult>,
+    // the delivery being edited, with the state rebuilt up to it
+    pub correction: Option<EventCorrection>,
+    // events in the log that could not be applied, with the reason why
+    invalid_events: Vec<(usize, String)>,
 }
 
 impl GameState {
     // ui
     pub fn update(&mut self, event: GameEvent) -> Option<Page> {
+        if event.is_ball() || event.is_batter_selection() {
+            if let Some(correction) = self.correction.take() {
+                let event_index = correction.event_index;
+                let events = correction.apply(event);
+
+                if let Some(page) = self.correct_missing_batter(&events, event_index) {
+                    return Some(page);
+                }
+
+                *self = GameState::from_events(events);
+                return self.next_page().or(Some(Page::Scoring));
+            }
+        }
+
         self.add_event(event.clone());
 
         let mut page = None;
@@ -42,80 +85,74 @@ impl GameState {
         match event {
             GameEvent::Runs(runs) => {
                 self.add_runs(&runs);
-
-                if self.is_end_over() {
-                    self.end_over();
-                    page = Some(Page::SelectBowler);
-                }
+                self.free_hit = false;
+                self.update_partnership();
+                page = self.end_ball();
             }
             GameEvent::Wicket(wicket_event) => {
                 self.add_wicket(&wicket_event);
 
-                if self.batting_team().wickets == 10 {
-                    self.update(GameEvent::EndInnings);
-                    self.update(GameEvent::StartInnings(self.batting_team.clone()));
-                    page = Some(Page::SelectBatter);
-                } else if self.is_end_over() {
-                    self.end_over();
-                    page = Some(Page::SelectBowler);
+                if wicket_event.extra.is_none() && wicket_event.how_out.rules().counts_as_ball {
+                    self.free_hit = false;
                 }
+
+                self.update_partnership();
+                page = self.end_ball();
             }
             GameEvent::SelectOnStrike(player) => {
-                let team = self.batting_team();
-                let batter_ref = Rc::clone(&team.players[player]);
+                let batter_ref = Rc::clone(&self.batting_team().players[player]);
+                let batting_order = self.next_batting_order();
                 let mut batter = batter_ref.borrow_mut();
 
-                if team.wickets == 0 {
-                    batter.batting_order = Some(0);
-                } else {
-                    batter.batting_order = Some((team.wickets + 1) as usize);
+                // a retired batter returning keeps their place in the order
+                if batter.batting_order.is_none() {
+                    batter.batting_order = Some(batting_order);
                 }
 
                 batter.how_out = HowOut::NotOut;
+                batter.wicket_details = None;
 
                 match self.on_strike_batter {
                     PlayerType::A => self.batter_a = Some(Rc::clone(&batter_ref)),
                     PlayerType::B => self.batter_b = Some(Rc::clone(&batter_ref)),
                 }
+
+                drop(batter);
+                self.start_partnership();
             }
             GameEvent::SelectOffStrike(player) => {
-                let team = self.batting_team();
-                let batter_ref = Rc::clone(&team.players[player]);
+                let batter_ref = Rc::clone(&self.batting_team().players[player]);
+                let batting_order = self.next_batting_order();
                 let mut batter = batter_ref.borrow_mut();
 
-                if team.wickets == 0 {
-                    batter.batting_order = Some(0);
-                } else {
-                    batter.batting_order = Some((team.wickets + 1) as usize);
+                // a retired batter returning keeps their place in the order
+                if batter.batting_order.is_none() {
+                    batter.batting_order = Some(batting_order);
                 }
 
                 batter.how_out = HowOut::NotOut;
+                batter.wicket_details = None;
 
                 match self.on_strike_batter {
                     PlayerType::A => self.batter_b = Some(Rc::clone(&batter_ref)),
                     PlayerType::B => self.batter_a = Some(Rc::clone(&batter_ref)),
                 }
-            }
-            GameEvent::SelectBowler(player) => {
-                let bowler_ref = Rc::clone(&self.bowling_team().players[player]);
-                let bowler = bowler_ref.borrow();
-
-                let bowling_order = match self.last_bowler {
-                    Some(_) => {
-                        if bowler.bowling_order == None {
-                            self.bowling_team().next_bowling_order()
-                        } else {
-                            bowler.bowling_order.unwrap()
-                        }
-                    }
-                    None => 0,
-                };
-                drop(bowler); // drop to borrow mut
 
-                let mut bowler = bowler_ref.borrow_mut();
-                bowler.bowling_order = Some(bowling_order);
+                drop(batter);
+                self.start_partnership();
+            }
+            GameEvent::SelectBowler(player) => self.select_bowler(player),
+            GameEvent::SwapStrike => self.change_strike(),
+            GameEvent::CorrectBatter(correction) => {
+                self.correct_batter(&correction);
+                self.update_partnership();
+            }
+            GameEvent::ReplaceBowler(player) => {
+                if let Some(bowler) = &self.bowler {
+                    self.replaced_bowlers.push(bowler.borrow().order);
+                }
 
-                self.bowler = Some(Rc::clone(&bowler_ref));
+                self.select_bowler(player);
             }
             GameEvent::SubmitTeam(team_name) => {
                 self.batting_team_mut().team_name = team_name;
@@ -123,54 +160,44 @@ impl GameState {
             }
             GameEvent::AddPlayer(player) => self.add_player(player),
             GameEvent::Extra(extra) => {
-                let batter_ref = Rc::clone(&self.on_strike_batter().unwrap());
-                let mut batter = batter_ref.borrow_mut();
-
-                let bowler_ref = Rc::clone(&self.bowler.as_ref().unwrap());
-                let mut bowler = bowler_ref.borrow_mut();
-
-                bowler.add_extra(&extra);
-
-                match extra.extra_type {
-                    ExtraType::NoBall => {
-                        batter.runs_scored += extra.runs;
-                        batter.balls_faced += 1;
-                    }
-                    ExtraType::Bye | ExtraType::LegBye => {
-                        batter.balls_faced += 1;
-                    }
-                    _ => (),
-                }
-
-                let batting_team = self.batting_team_mut();
-                batting_team.add_extra(&extra);
+                self.add_extra(&extra);
+                self.update_partnership();
+                page = self.end_ball();
+            }
+            GameEvent::StartGame(match_config) => self.match_config = match_config,
+            GameEvent::Declare => {
+                self.end_innings(InningsStatus::Declared);
+                page = self.next_page();
+            }
+            GameEvent::ForfeitInnings => {
+                self.end_innings(InningsStatus::Forfeited);
+                page = self.next_page();
+            }
+            GameEvent::Substitute(substitution) => self.substitute(substitution),
+            GameEvent::ChangeKeeper(player) => self.bowling_team_mut().set_wicket_keeper(player),
+            GameEvent::ChangeCaptain(player) => self.bowling_team_mut().set_captain(player),
+            GameEvent::Retire(retirement) => {
+                self.update_partnership();
+                self.retire_batter(&retirement);
+                page = self.next_page();
+            }
+            GameEvent::PenaltyRuns(penalty) => {
+                self.add_penalty_runs(&penalty);
 
-                match extra.extra_type {
-                    ExtraType::Bye | ExtraType::LegBye => {
-                        batting_team.overs.add_ball();
-                    }
-                    _ => (),
+                if self.innings_in_progress && self.is_end_innings() {
+                    self.end_innings(InningsStatus::Completed);
                 }
 
-                if extra.runs % 2 == 1 {
-                    self.change_strike();
-                }
+                page = self.next_page();
+            }
+            GameEvent::EndMatch => {
+                self.end_match();
+                page = Some(Page::Result);
             }
             GameEvent::StartInnings(team_type) => {
+                self.follow_on = self.last_innings_team() == Some(team_type.clone());
                 self.batting_team = team_type;
-            }
-            GameEvent::EndInnings => {
-                self.batting_team = match self.batting_team {
-                    TeamType::A => TeamType::B,
-                    TeamType::B => TeamType::A,
-                };
-
-                self.batter_a = None;
-                self.batter_b = None;
-                self.on_strike_batter = PlayerType::A;
-                self.bowler = None;
-                self.last_bowler = None;
-                self.last_last_bowler = None;
+                self.innings_in_progress = true;
             }
             _ => (),
         }
@@ -188,13 +215,30 @@ impl GameState {
                 wickets = team.wickets,
                 runs = team.runs
             )),
-            text(format!(
-                "Overs: {overs}.{balls}",
-                overs = team.overs.overs,
-                balls = team.overs.balls
-            ))
+            text(match self.match_config.overs_per_innings {
+                Some(overs_per_innings) => format!(
+                    "Overs: {overs}.{balls}/{overs_per_innings}",
+                    overs = team.overs.overs,
+                    balls = team.overs.balls
+                ),
+                None => format!(
+                    "Overs: {overs}.{balls}",
+                    overs = team.overs.overs,
+                    balls = team.overs.balls
+                ),
+            })
         ];
 
+        if self.free_hit {
+            content = content.push(text("FREE HIT"));
+        }
+
+        content = content.push(self.invalid_event_column());
+
+        if let Some(chase_column) = self.chase_column() {
+            content = content.push(chase_column);
+        }
+
         if let Some(player) = &self.batter_a {
             let player = Rc::clone(player);
             let mut batting_container = Row::<Event>::new();
@@ -225,22 +269,121 @@ impl GameState {
         }
 
         content = content.push(row![
-            button("0").on_press(Event::GameEvent(GameEvent::Runs(0))),
-            button("1").on_press(Event::GameEvent(GameEvent::Runs(1))),
-            button("2").on_press(Event::GameEvent(GameEvent::Runs(2))),
-            button("3").on_press(Event::GameEvent(GameEvent::Runs(3))),
-            button("4").on_press(Event::GameEvent(GameEvent::Runs(4))),
-            button("6").on_press(Event::GameEvent(GameEvent::Runs(6))),
+            button("0").on_press(Event::GameEvent(GameEvent::Runs(Runs::new(0)))),
+            button("1").on_press(Event::GameEvent(GameEvent::Runs(Runs::new(1)))),
+            button("2").on_press(Event::GameEvent(GameEvent::Runs(Runs::new(2)))),
+            button("3").on_press(Event::GameEvent(GameEvent::Runs(Runs::new(3)))),
+            button("4 run").on_press(Event::GameEvent(GameEvent::Runs(Runs::new(4)))),
+            button("5 run").on_press(Event::GameEvent(GameEvent::Runs(Runs::new(5)))),
+            button("FOUR").on_press(Event::GameEvent(GameEvent::Runs(Runs::boundary(
+                Boundary::Four
+            )))),
+            button("SIX").on_press(Event::GameEvent(GameEvent::Runs(Runs::boundary(
+                Boundary::Six
+            )))),
+            button("runs...").on_press(Event::ChangePage(Page::SelectRuns)),
             button("wicket").on_press(Event::ChangePage(Page::SelectWicket)),
             button("extra").on_press(Event::ChangePage(Page::SelectExtra)),
+            button("penalty").on_press(Event::ChangePage(Page::SelectPenalty)),
+            button("retire").on_press(Event::ChangePage(Page::SelectRetirement)),
+            button("swap strike").on_press(Event::GameEvent(GameEvent::SwapStrike)),
+            button("correct batter").on_press(Event::ChangePage(Page::CorrectBatter)),
+            button("change captain/keeper").on_press(Event::ChangePage(Page::SelectKeeper)),
+            button("substitution").on_press(Event::ChangePage(Page::SelectSubstitution)),
+            button("undo").on_press(Event::Undo),
+            button("Save Game").on_press(Event::SaveGame),
+            button("Scorecard").on_press(Event::ChangePage(Page::Scorecard)),
+            button("End match").on_press(Event::GameEvent(GameEvent::EndMatch)),
+        ]);
+
+        // only a bowler part way through an over can be replaced
+        if self.bowler.is_some() {
+            content = content
+                .push(button("replace bowler").on_press(Event::ChangePage(Page::ReplaceBowler)));
+        }
+
+        if self.can_declare() {
+            content =
+                content.push(button("Declare").on_press(Event::GameEvent(GameEvent::Declare)));
+        }
+
+        content = content.push(scrollable(self.event_column()));
+
+        content.into()
+    }
+
+    pub fn result_view(&self) -> Element<'_, Event> {
+        let mut content = Column::new();
+
+        if let Some(result) = &self.result {
+            content = content.push(text(result.describe(self)));
+        }
+
+        content = content.push(self.invalid_event_column());
+
+        for (team_type, innings) in self.all_innings() {
+            content = content.push(text(self.innings_description(&team_type, &innings)));
+        }
+
+        content = content.push(row![
             button("undo").on_press(Event::Undo),
             button("Save Game").on_press(Event::SaveGame),
+            button("Scorecard").on_press(Event::ChangePage(Page::Scorecard)),
         ]);
         content = content.push(scrollable(self.event_column()));
 
         content.into()
     }
 
+    pub fn innings_description(&self, team_type: &TeamType, innings: &Innings) -> String {
+        let team_name = &self.team(team_type).team_name;
+        let follow_on = if innings.follow_on { " (f/o)" } else { "" };
+
+        match innings.status {
+            InningsStatus::Forfeited => format!("{team_name}{follow_on}: innings forfeited"),
+            InningsStatus::Declared => format!(
+                "{team_name}{follow_on}: {wickets}d/{runs} ({overs})",
+                wickets = innings.wickets,
+                runs = innings.runs,
+                overs = innings.overs.to_string()
+            ),
+            _ => format!(
+                "{team_name}{follow_on}: {wickets}/{runs} ({overs})",
+                wickets = innings.wickets,
+                runs = innings.runs,
+                overs = innings.overs.to_string()
+            ),
+        }
+    }
+
+    fn chase_column(&self) -> Option<Column<'_, Event>> {
+        let target = self.target()?;
+        let runs_needed = self.runs_needed()?;
+
+        let mut column = Column::new();
+        column = column.push(text(format!("Target: {target}")));
+
+        column = column.push(text(match self.balls_remaining() {
+            Some(balls) => format!(
+                "Need {runs_needed} run{runs_plural} from {balls} ball{balls_plural}",
+                runs_plural = if runs_needed == 1 { "" } else { "s" },
+                balls_plural = if balls == 1 { "" } else { "s" }
+            ),
+            None => format!(
+                "Need {runs_needed} run{plural}",
+                plural = if runs_needed == 1 { "" } else { "s" }
+            ),
+        }));
+
+        let mut run_rates = format!("CRR: {:.2}", self.current_run_rate());
+        if let Some(required_run_rate) = self.required_run_rate() {
+            run_rates += &format!(", RRR: {:.2}", required_run_rate);
+        }
+        column = column.push(text(run_rates));
+
+        Some(column)
+    }
+
     fn event_column(&self) -> Column<Event> {
         let mut column = Column::new();
 
@@ -255,6 +398,26 @@ impl GameState {
         column
     }
 
+    fn invalid_event_column(&self) -> Column<'_, Event> {
+        let mut column = Column::new();
+
+        for (index, reason) in &self.invalid_events {
+            let mut row = row![text(format!(
+                "Warning: event {} was skipped, {reason}",
+                index + 1
+            ))];
+
+            if let Some(event_container) = self.events[*index].to_container() {
+                row = row.push(event_container);
+            }
+
+            row = row.push(button("Delete").on_press(Event::DeleteEvent(*index)));
+            column = column.push(row);
+        }
+
+        column
+    }
+
     pub fn player_column(&self) -> Column<Event> {
         let team = self.batting_team();
         let mut column = Column::new();
@@ -271,6 +434,7 @@ impl GameState {
     // business logic
     pub fn new() -> Self {
         GameState {
+            match_config: MatchConfig::default(),
             team_a: Team::new(),
             team_b: Team::new(),
             events: vec![],
@@ -282,6 +446,15 @@ impl GameState {
             bowler: None,
             last_bowler: None,
             last_last_bowler: None,
+            replaced_bowlers: vec![],
+            last_replaced_bowlers: vec![],
+            innings_in_progress: false,
+            follow_on: false,
+            free_hit: false,
+            over_start_runs_conceded: 0,
+            result: None,
+            correction: None,
+            invalid_events: vec![],
         }
     }
 
@@ -289,12 +462,109 @@ impl GameState {
         let mut game_state = GameState::new();
 
         for event in events {
+            // derived events are added again by the events that caused them
+            if event.is_derived() {
+                continue;
+            }
+
+            // an edit earlier in the log can leave later events with nothing to
+            // apply to, so they are kept for the scorer to fix but not applied
+            if let Some(reason) = game_state.invalid_reason(&event) {
+                game_state
+                    .invalid_events
+                    .push((game_state.events.len(), reason));
+                game_state.events.push(event);
+                continue;
+            }
+
             game_state.update(event);
         }
 
         game_state
     }
 
+    // rebuilds the state as it was before the delivery being corrected
+    pub fn for_correction(correction: EventCorrection) -> Self {
+        let mut game_state =
+            GameState::from_events(correction.events[..correction.event_index].to_vec());
+        game_state.correction = Some(correction);
+
+        game_state
+    }
+
+    // a corrected delivery that is now a wicket needs the new batter selected
+    // straight after it, before the rest of the log is applied
+    fn correct_missing_batter(&mut self, events: &[GameEvent], event_index: usize) -> Option<Page> {
+        let game_state = GameState::from_events(events[..=event_index].to_vec());
+
+        if !game_state.innings_in_progress || game_state.batter_to_replace().is_none() {
+            return None;
+        }
+
+        let batter_selected = events[event_index + 1..]
+            .iter()
+            .find(|event| !event.is_derived())
+            .is_some_and(GameEvent::is_batter_selection);
+
+        if batter_selected {
+            return None;
+        }
+
+        let correction = EventCorrection::new(events.to_vec(), event_index + 1, true);
+        *self = GameState::for_correction(correction);
+
+        Some(Page::SelectBatter)
+    }
+
+    pub fn cancel_correction(&mut self) {
+        if let Some(correction) = self.correction.take() {
+            *self = GameState::from_events(correction.events);
+        }
+    }
+
+    fn invalid_reason(&self, event: &GameEvent) -> Option<String> {
+        let reason = match event {
+            _ if event.is_ball() && !self.innings_in_progress => "no innings is in progress",
+            _ if event.is_ball() && self.batter_to_replace().is_some() => {
+                "a batter needs to be selected first"
+            }
+            _ if event.is_ball() && self.bowler.is_none() => "no bowler has been selected",
+            GameEvent::SelectOnStrike(player) | GameEvent::SelectOffStrike(player) => {
+                let Some(batter) = self.batting_team().players.get(*player) else {
+                    return Some(String::from("no such player"));
+                };
+                let batter = batter.borrow();
+
+                if self.batter_to_replace().is_none() {
+                    "both batters are already at the crease"
+                } else if batter.reserve {
+                    "the player is a reserve who hasn't been substituted on"
+                } else if batter.how_out != HowOut::DidNotBat && !batter.how_out.is_retirement() {
+                    "the batter has already batted"
+                } else {
+                    return None;
+                }
+            }
+            GameEvent::SelectBowler(_) if self.bowler.is_some() => {
+                "a bowler is already bowling this over"
+            }
+            GameEvent::ReplaceBowler(_) if self.bowler.is_none() => "no bowler is bowling",
+            GameEvent::Declare if !self.can_declare() => "the innings can't be declared",
+            GameEvent::ForfeitInnings if !self.can_forfeit() => "the innings can't be forfeited",
+            GameEvent::Substitute(substitution) => self.substitution_error(substitution)?,
+            GameEvent::Retire(Retirement { batter, .. })
+            | GameEvent::CorrectBatter(BatterCorrection { batter, .. })
+                if self.batter(batter).is_none() =>
+            {
+                "the batter is not at the crease"
+            }
+            GameEvent::CorrectBatter(correction) => self.batter_correction_error(correction)?,
+            _ => return None,
+        };
+
+        Some(String::from(reason))
+    }
+
     pub fn batting_team(&self) -> &Team {
         let team = match self.batting_team {
             TeamType::A => &self.team_a,
@@ -313,6 +583,34 @@ impl GameState {
         team
     }
 
+    pub fn team(&self, team_type: &TeamType) -> &Team {
+        match team_type {
+            TeamType::A => &self.team_a,
+            TeamType::B => &self.team_b,
+        }
+    }
+
+    pub fn team_mut(&mut self, team_type: &TeamType) -> &mut Team {
+        match team_type {
+            TeamType::A => &mut self.team_a,
+            TeamType::B => &mut self.team_b,
+        }
+    }
+
+    pub fn other_team(&self, team_type: &TeamType) -> &Team {
+        match team_type {
+            TeamType::A => &self.team_b,
+            TeamType::B => &self.team_a,
+        }
+    }
+
+    pub fn bowling_team_mut(&mut self) -> &mut Team {
+        match self.batting_team {
+            TeamType::A => &mut self.team_b,
+            TeamType::B => &mut self.team_a,
+        }
+    }
+
     pub fn bowling_team(&self) -> &Team {
         let team = match self.batting_team {
             TeamType::A => &self.team_b,
@@ -363,7 +661,9 @@ impl GameState {
         }
     }
 
-    fn add_runs(&mut self, runs: &u32) {
+    fn add_runs(&mut self, runs: &Runs) {
+        let total_runs = runs.total_runs();
+
         let on_strike_batter = Rc::clone(
             &self
                 .on_strike_batter()
@@ -372,7 +672,15 @@ impl GameState {
         let mut on_strike_batter = on_strike_batter.borrow_mut();
 
         on_strike_batter.balls_faced += 1;
-        on_strike_batter.runs_scored += runs;
+        on_strike_batter.runs_scored += total_runs;
+
+        if runs.is_four() {
+            on_strike_batter.fours += 1;
+        }
+
+        if runs.is_six() {
+            on_strike_batter.sixes += 1;
+        }
 
         let bowler = Rc::clone(
             &self
@@ -382,35 +690,138 @@ impl GameState {
         );
         let mut bowler = bowler.borrow_mut();
 
-        bowler.overs_bowled.add_ball_bowler();
-        bowler.runs_conceded += runs;
+        bowler
+            .overs_bowled
+            .add_ball_bowler(self.match_config.balls_per_over);
+        bowler.runs_conceded += total_runs;
+
+        if total_runs == 0 {
+            bowler.dot_balls += 1;
+        }
 
         let team = self.batting_team_mut();
-        team.runs += runs;
+        team.runs += total_runs;
         team.overs.add_ball();
 
-        if runs % 2 == 1 {
+        if runs.changes_strike() {
+            self.change_strike();
+        }
+    }
+
+    fn add_extra(&mut self, extra: &Extra) {
+        let batter_ref = Rc::clone(&self.on_strike_batter().unwrap());
+        let mut batter = batter_ref.borrow_mut();
+
+        let bowler_ref = Rc::clone(&self.bowler.as_ref().unwrap());
+        let mut bowler = bowler_ref.borrow_mut();
+
+        bowler.add_extra(extra, self.match_config.balls_per_over);
+
+        match extra.extra_type {
+            ExtraType::NoBall => {
+                batter.runs_scored += extra.bat_runs();
+                batter.balls_faced += 1;
+
+                match extra.bat_boundary() {
+                    Some(Boundary::Four) => batter.fours += 1,
+                    Some(Boundary::Six) => batter.sixes += 1,
+                    _ => (),
+                }
+            }
+            ExtraType::Bye | ExtraType::LegBye => {
+                batter.balls_faced += 1;
+            }
+            _ => (),
+        }
+
+        drop(batter);
+        drop(bowler);
+
+        let batting_team = self.batting_team_mut();
+        batting_team.add_extra(extra);
+
+        if extra.is_legal_ball() {
+            batting_team.overs.add_ball();
+        }
+
+        if extra.runs % 2 == 1 {
             self.change_strike();
         }
+
+        self.free_hit = match extra.extra_type {
+            ExtraType::NoBall => self.match_config.free_hits,
+            ExtraType::Wide => self.free_hit,
+            _ => false,
+        };
     }
 
     fn add_wicket(&mut self, wicket_event: &WicketEvent) {
-        let player = Rc::clone(
+        let mut rules = wicket_event.how_out.rules();
+        let runs = wicket_event.runs_completed();
+
+        // the extra decides whether the delivery counts as a ball
+        if let Some(extra) = &wicket_event.extra {
+            self.add_extra(extra);
+            rules.counts_as_ball = false;
+        }
+
+        let on_strike_batter = Rc::clone(
             &self
                 .on_strike_batter()
-                .expect("There should be an on strike batter when a wicket occurs")
-                .to_owned(),
+                .expect("There should be an on strike batter when a wicket occurs"),
+        );
+        let mut on_strike_batter = on_strike_batter.borrow_mut();
+
+        if rules.counts_as_ball {
+            on_strike_batter.balls_faced += 1;
+        }
+
+        // runs completed before a run out still count to the striker
+        on_strike_batter.runs_scored += runs;
+        drop(on_strike_batter);
+
+        let out_batter_type = match &wicket_event.run_out {
+            Some(run_out) => run_out.batter.clone(),
+            None => self.on_strike_batter.clone(),
+        };
+        let player = Rc::clone(
+            &self
+                .batter(&out_batter_type)
+                .expect("The dismissed batter should be at the crease"),
         );
         let mut player = player.borrow_mut();
+
+        let assist = wicket_event
+            .run_out
+            .as_ref()
+            .and_then(|run_out| run_out.assist);
+        let is_substitute = |order: Option<usize>| {
+            order.is_some_and(|order| self.bowling_team().players[order].borrow().reserve)
+        };
+
         player.how_out = wicket_event.how_out.clone();
-        player.wicket_details = Some(WicketDetails::new(
-            wicket_event.bowler,
-            wicket_event.fielder,
-        ));
+        player.wicket_details = Some(
+            WicketDetails::new(wicket_event.bowler, wicket_event.fielder)
+                .with_assist(assist)
+                .with_substitutes(is_substitute(wicket_event.fielder), is_substitute(assist)),
+        );
 
         let team = self.batting_team_mut();
-        team.wickets += 1;
-        team.overs.add_ball();
+        team.runs += runs;
+
+        if rules.counts_as_wicket {
+            team.wickets += 1;
+        }
+
+        if rules.counts_as_ball {
+            team.overs.add_ball();
+        }
+
+        if rules.counts_as_wicket {
+            let fall_of_wicket =
+                FallOfWicket::new(team.wickets, team.runs, player.order, team.overs.clone());
+            team.fall_of_wickets.push(fall_of_wicket);
+        }
 
         let bowler = Rc::clone(
             &self
@@ -419,69 +830,712 @@ impl GameState {
                 .expect("There should be a bowler when a wicket occurs"),
         );
         let mut bowler = bowler.borrow_mut();
-        bowler.wickets_taken += 1;
-        bowler.overs_bowled.add_ball_bowler();
+        bowler.runs_conceded += runs;
 
-        self.set_on_strike_batter(None);
-    }
+        if rules.credits_bowler {
+            bowler.wickets_taken += 1;
+        }
 
-    pub fn batter_to_replace(&self) -> Option<ReplaceBatter> {
-        if (self.on_strike_batter == PlayerType::A && self.batter_a == None)
-            || (self.on_strike_batter == PlayerType::B && self.batter_b == None)
-        {
-            return Some(ReplaceBatter::OnStrike);
+        if rules.counts_as_ball {
+            bowler
+                .overs_bowled
+                .add_ball_bowler(self.match_config.balls_per_over);
+
+            if runs == 0 {
+                bowler.dot_balls += 1;
+            }
         }
 
-        if (self.on_strike_batter == PlayerType::B && self.batter_a == None)
-            || (self.on_strike_batter == PlayerType::A && self.batter_b == None)
-        {
-            return Some(ReplaceBatter::OffStrike);
+        drop(bowler);
+        self.credit_fielders(wicket_event);
+
+        // the incoming batter takes the end where the wicket fell
+        if let Some(run_out) = &wicket_event.run_out {
+            self.on_strike_batter = match (&run_out.end, &out_batter_type) {
+                (CreaseEnd::Striker, _) => out_batter_type.clone(),
+                (CreaseEnd::NonStriker, PlayerType::A) => PlayerType::B,
+                (CreaseEnd::NonStriker, PlayerType::B) => PlayerType::A,
+            };
         }
 
-        None
+        self.set_batter(&out_batter_type, None);
     }
 
-    pub fn on_strike_batter(&self) -> Option<Rc<RefCell<Player>>> {
-        let batter = match self.on_strike_batter {
-            PlayerType::A => &self.batter_a,
-            PlayerType::B => &self.batter_b,
-        };
+    fn credit_fielders(&self, wicket_event: &WicketEvent) {
+        let bowling_team = self.bowling_team();
+        let fielder = wicket_event
+            .fielder
+            .map(|fielder| Rc::clone(&bowling_team.players[fielder]));
+
+        match wicket_event.how_out {
+            HowOut::Caught => {
+                if let Some(fielder) = fielder {
+                    let mut fielder = fielder.borrow_mut();
+
+                    if fielder.wicket_keeper {
+                        fielder.fielding.keeper_catches += 1;
+                    } else {
+                        fielder.fielding.catches += 1;
+                    }
+                }
+            }
+            HowOut::Stumped => {
+                if let Some(keeper) = fielder.or(bowling_team.wicket_keeper()) {
+                    keeper.borrow_mut().fielding.stumpings += 1;
+                }
+            }
+            HowOut::RunOut => {
+                let Some(run_out) = &wicket_event.run_out else {
+                    return;
+                };
 
-        let mut player_ref = None;
+                if let Some(fielder) = fielder {
+                    let mut fielder = fielder.borrow_mut();
 
-        if let Some(player) = batter {
-            player_ref = Some(Rc::clone(player));
+                    match run_out.direct_hit {
+                        true => fielder.fielding.direct_hits += 1,
+                        false => fielder.fielding.run_outs += 1,
+                    }
+                }
+
+                if let Some(assist) = run_out.assist.filter(|_| !run_out.direct_hit) {
+                    bowling_team.players[assist]
+                        .borrow_mut()
+                        .fielding
+                        .run_out_assists += 1;
+                }
+            }
+            _ => (),
         }
+    }
 
-        player_ref
+    fn select_bowler(&mut self, player: usize) {
+        let bowler_ref = Rc::clone(&self.bowling_team().players[player]);
+        let bowler = bowler_ref.borrow();
+
+        let bowling_order = match bowler.bowling_order {
+            Some(bowling_order) => bowling_order,
+            None if self.bowling_team().bowled_players_in_order().is_empty() => 0,
+            None => self.bowling_team().next_bowling_order(),
+        };
+        drop(bowler); // drop to borrow mut
+
+        let mut bowler = bowler_ref.borrow_mut();
+        bowler.bowling_order = Some(bowling_order);
+        self.over_start_runs_conceded = bowler.runs_conceded;
+
+        self.bowler = Some(Rc::clone(&bowler_ref));
     }
 
-    pub fn set_on_strike_batter(&mut self, batter: Option<Rc<RefCell<Player>>>) {
-        match self.on_strike_batter {
-            PlayerType::A => self.batter_a = batter,
-            PlayerType::B => self.batter_b = batter,
+    fn substitute(&mut self, substitution: Substitution) {
+        if self.substitution_error(&substitution).is_some() {
+            return;
         }
+
+        let innings = self.innings_completed() + 1;
+        let overs = self.batting_team().overs.clone();
+        let team = self.team_mut(&substitution.team);
+
+        if substitution.kind.replaces_player() {
+            team.players[substitution.player_off].borrow_mut().reserve = true;
+            team.players[substitution.player_on].borrow_mut().reserve = false;
+        }
+
+        team.substitutions
+            .push(SubstitutionRecord::new(substitution, innings, overs));
     }
 
-    fn is_end_over(&self) -> bool {
-        self.batting_team().overs.balls >= 6
+    fn retire_batter(&mut self, retirement: &Retirement) {
+        let batter = self
+            .batter(&retirement.batter)
+            .expect("A retiring batter should be at the crease");
+        batter.borrow_mut().how_out = retirement.how_out.clone();
+
+        self.set_batter(&retirement.batter, None);
     }
 
-    fn end_over(&mut self) {
-        self.last_last_bowler = self.last_bowler;
-        self.last_bowler = Some(
-            self.bowler
-                .as_ref()
-                .expect("There should be a bowler")
-                .borrow()
-                .order,
-        );
-        self.bowler = None;
+    // the innings so far of the batter entered belongs to the player who is
+    // really at the crease, who hasn't batted yet
+    fn correct_batter(&mut self, correction: &BatterCorrection) {
+        if self.batter_correction_error(correction).is_some() {
+            return;
+        }
 
-        self.batting_team_mut().overs.end_over();
-        self.change_strike();
+        let Some(entered_ref) = self.batter(&correction.batter) else {
+            return;
+        };
+        let batter_ref = Rc::clone(&self.batting_team().players[correction.player]);
 
-        let team = self.batting_team();
+        if Rc::ptr_eq(&entered_ref, &batter_ref) {
+            return;
+        }
+
+        let mut entered = entered_ref.borrow_mut();
+        let mut batter = batter_ref.borrow_mut();
+
+        batter.batting_order = entered.batting_order.take();
+        batter.how_out = std::mem::replace(&mut entered.how_out, HowOut::DidNotBat);
+        batter.runs_scored = std::mem::take(&mut entered.runs_scored);
+        batter.balls_faced = std::mem::take(&mut entered.balls_faced);
+        batter.fours = std::mem::take(&mut entered.fours);
+        batter.sixes = std::mem::take(&mut entered.sixes);
+
+        let (entered_order, batter_order) = (entered.order, batter.order);
+        drop(entered);
+        drop(batter);
+
+        for partnership in &mut self.batting_team_mut().partnerships {
+            for partnership_batter in &mut partnership.batters {
+                if partnership_batter.order == entered_order {
+                    partnership_batter.order = batter_order;
+                }
+            }
+        }
+
+        self.set_batter(&correction.batter, Some(batter_ref));
+    }
+
+    fn start_partnership(&mut self) {
+        let (Some(batter_a), Some(batter_b)) = (self.batter_a.clone(), self.batter_b.clone())
+        else {
+            return;
+        };
+
+        let balls_per_over = self.match_config.balls_per_over;
+        let team = self.batting_team_mut();
+        let partnership = Partnership::new(
+            [&batter_a.borrow(), &batter_b.borrow()],
+            team.runs,
+            team.overs.total_balls(balls_per_over),
+        );
+        team.partnerships.push(partnership);
+    }
+
+    fn update_partnership(&mut self) {
+        let balls_per_over = self.match_config.balls_per_over;
+        let team = self.batting_team_mut();
+        let team_balls = team.overs.total_balls(balls_per_over);
+
+        if let Some(partnership) = team.partnerships.last_mut() {
+            partnership.update(team.runs, team_balls, &team.players);
+        }
+    }
+
+    fn next_batting_order(&self) -> usize {
+        self.batting_team()
+            .players
+            .iter()
+            .filter(|player| player.borrow().batting_order.is_some())
+            .count()
+    }
+
+    fn add_penalty_runs(&mut self, penalty: &Penalty) {
+        let is_batting = self.innings_in_progress && self.batting_team == penalty.team;
+        let team = match penalty.team {
+            TeamType::A => &mut self.team_a,
+            TeamType::B => &mut self.team_b,
+        };
+
+        // penalty runs for the fielding side go to its most recent innings, or
+        // to its next innings if it hasn't batted yet
+        if !is_batting {
+            if let Some(innings) = team.innings.last_mut() {
+                innings.add_penalty_runs(penalty.runs);
+                return;
+            }
+        }
+
+        team.add_penalty_runs(penalty.runs);
+    }
+
+    pub fn batter_to_replace(&self) -> Option<ReplaceBatter> {
+        if (self.on_strike_batter == PlayerType::A && self.batter_a == None)
+            || (self.on_strike_batter == PlayerType::B && self.batter_b == None)
+        {
+            return Some(ReplaceBatter::OnStrike);
+        }
+
+        if (self.on_strike_batter == PlayerType::B && self.batter_a == None)
+            || (self.on_strike_batter == PlayerType::A && self.batter_b == None)
+        {
+            return Some(ReplaceBatter::OffStrike);
+        }
+
+        None
+    }
+
+    pub fn on_strike_batter(&self) -> Option<Rc<RefCell<Player>>> {
+        let batter = match self.on_strike_batter {
+            PlayerType::A => &self.batter_a,
+            PlayerType::B => &self.batter_b,
+        };
+
+        let mut player_ref = None;
+
+        if let Some(player) = batter {
+            player_ref = Some(Rc::clone(player));
+        }
+
+        player_ref
+    }
+
+    pub fn set_on_strike_batter(&mut self, batter: Option<Rc<RefCell<Player>>>) {
+        match self.on_strike_batter {
+            PlayerType::A => self.batter_a = batter,
+            PlayerType::B => self.batter_b = batter,
+        }
+    }
+
+    pub fn batter(&self, player_type: &PlayerType) -> Option<Rc<RefCell<Player>>> {
+        match player_type {
+            PlayerType::A => self.batter_a.as_ref().map(Rc::clone),
+            PlayerType::B => self.batter_b.as_ref().map(Rc::clone),
+        }
+    }
+
+    fn set_batter(&mut self, player_type: &PlayerType, batter: Option<Rc<RefCell<Player>>>) {
+        match player_type {
+            PlayerType::A => self.batter_a = batter,
+            PlayerType::B => self.batter_b = batter,
+        }
+    }
+
+    // why a player can't bowl the next over, if they can't
+    pub fn bowler_restriction(&self, order: usize) -> Option<BowlerRestriction> {
+        let is_bowling = self
+            .bowler
+            .as_ref()
+            .is_some_and(|bowler| bowler.borrow().order == order);
+
+        if is_bowling || self.replaced_bowlers.contains(&order) {
+            return Some(BowlerRestriction::BowlingThisOver);
+        }
+
+        if self.last_bowler == Some(order) || self.last_replaced_bowlers.contains(&order) {
+            return Some(BowlerRestriction::BowledLastOver);
+        }
+
+        let max_overs = self.match_config.max_overs_per_bowler?;
+        let balls_per_over = self.match_config.balls_per_over;
+        let balls_bowled = self.bowling_team().players[order]
+            .borrow()
+            .overs_bowled
+            .total_balls(balls_per_over);
+
+        // a bowler who finished someone else's over may not have room for a full one
+        if balls_bowled + balls_per_over > max_overs * balls_per_over {
+            return Some(BowlerRestriction::QuotaUsed(max_overs));
+        }
+
+        None
+    }
+
+    // batters at the crease and the bowler can't be taken off during play
+    pub fn is_batting_or_bowling(&self, player: &Rc<RefCell<Player>>) -> bool {
+        [&self.batter_a, &self.batter_b, &self.bowler]
+            .into_iter()
+            .flatten()
+            .any(|in_play| Rc::ptr_eq(in_play, player))
+    }
+
+    pub fn substitution_error(&self, substitution: &Substitution) -> Option<&'static str> {
+        let team = self.team(&substitution.team);
+
+        let (Some(player_off), Some(player_on)) = (
+            team.players.get(substitution.player_off),
+            team.players.get(substitution.player_on),
+        ) else {
+            return Some("no such player");
+        };
+
+        let reason = if player_off.borrow().reserve {
+            "the player coming off is not in the side"
+        } else if self.is_batting_or_bowling(player_off) {
+            "the player coming off is batting or bowling"
+        } else if !player_on.borrow().reserve {
+            "the player coming on is already in the side"
+        } else if team.was_replaced(substitution.player_on) {
+            "the player coming on has already been replaced"
+        } else {
+            return None;
+        };
+
+        Some(reason)
+    }
+
+    // the player really at the crease can't have batted yet, or their own
+    // innings would be overwritten
+    fn batter_correction_error(&self, correction: &BatterCorrection) -> Option<&'static str> {
+        let Some(batter) = self.batting_team().players.get(correction.player) else {
+            return Some("no such player");
+        };
+        let batter = batter.borrow();
+
+        let reason = if batter.reserve {
+            "the player is a reserve who hasn't been substituted on"
+        } else if batter.how_out != HowOut::DidNotBat {
+            "the batter has already batted"
+        } else {
+            return None;
+        };
+
+        Some(reason)
+    }
+
+    pub fn innings_completed(&self) -> usize {
+        self.team_a.innings.len() + self.team_b.innings.len()
+    }
+
+    pub fn is_final_innings(&self) -> bool {
+        self.innings_completed() + 1 == self.match_config.total_innings()
+    }
+
+    pub fn last_innings_team(&self) -> Option<TeamType> {
+        let last_innings_a = self.team_a.innings.last().map(|innings| innings.number);
+        let last_innings_b = self.team_b.innings.last().map(|innings| innings.number);
+
+        match (last_innings_a, last_innings_b) {
+            (None, None) => None,
+            (Some(_), None) => Some(TeamType::A),
+            (None, Some(_)) => Some(TeamType::B),
+            (Some(a), Some(b)) => Some(if a > b { TeamType::A } else { TeamType::B }),
+        }
+    }
+
+    pub fn first_innings_team(&self) -> Option<TeamType> {
+        match self.team_a.innings.first() {
+            Some(innings) if innings.number == 0 => Some(TeamType::A),
+            _ => self.team_b.innings.first().map(|_| TeamType::B),
+        }
+    }
+
+    // only timed matches are declared, and declaring the final innings would
+    // just end the match
+    pub fn can_declare(&self) -> bool {
+        self.innings_in_progress
+            && self.match_config.overs_per_innings.is_none()
+            && !self.is_final_innings()
+    }
+
+    // an innings is forfeited like a declaration made before it starts
+    pub fn can_forfeit(&self) -> bool {
+        let team = self.batting_team();
+
+        self.can_declare()
+            && self.batter_a.is_none()
+            && self.batter_b.is_none()
+            && team.runs == 0
+            && team.overs == Overs::new()
+    }
+
+    pub fn is_follow_on_available(&self) -> bool {
+        if self.match_config.innings_per_side != 2 || self.innings_completed() != 2 {
+            return false;
+        }
+
+        let first_innings_team = match self.first_innings_team() {
+            Some(team_type) => team_type,
+            None => return false,
+        };
+        let lead = self.team(&first_innings_team).total_runs() as i64
+            - self.other_team(&first_innings_team).total_runs() as i64;
+
+        lead >= self.match_config.follow_on_margin as i64
+    }
+
+    pub fn all_innings(&self) -> Vec<(TeamType, Innings)> {
+        let mut all_innings: Vec<(TeamType, Innings)> = self
+            .team_a
+            .innings
+            .iter()
+            .map(|innings| (TeamType::A, innings.clone()))
+            .chain(
+                self.team_b
+                    .innings
+                    .iter()
+                    .map(|innings| (TeamType::B, innings.clone())),
+            )
+            .collect();
+
+        if self.innings_in_progress {
+            all_innings.push((
+                self.batting_team.clone(),
+                Innings::new(
+                    self.innings_completed(),
+                    self.batting_team(),
+                    self.bowling_team(),
+                    self.follow_on,
+                ),
+            ));
+        }
+
+        all_innings.sort_by_key(|(_, innings)| innings.number);
+        all_innings
+    }
+
+    pub fn is_match_over(&self) -> bool {
+        self.result.is_some()
+    }
+
+    pub fn target(&self) -> Option<u32> {
+        if !self.is_final_innings() {
+            return None;
+        }
+
+        let deficit =
+            self.bowling_team().total_runs() as i64 - self.batting_team().total_runs() as i64;
+
+        Some((deficit + 1).max(0) as u32)
+    }
+
+    pub fn runs_needed(&self) -> Option<u32> {
+        Some(self.target()?.saturating_sub(self.batting_team().runs))
+    }
+
+    pub fn balls_remaining(&self) -> Option<u32> {
+        let balls_bowled = self
+            .batting_team()
+            .overs
+            .total_balls(self.match_config.balls_per_over);
+
+        Some(
+            self.match_config
+                .balls_per_innings()?
+                .saturating_sub(balls_bowled),
+        )
+    }
+
+    pub fn current_run_rate(&self) -> f64 {
+        let team = self.batting_team();
+        let balls_bowled = team.overs.total_balls(self.match_config.balls_per_over);
+
+        if balls_bowled == 0 {
+            return 0.0;
+        }
+
+        team.runs as f64 * self.match_config.balls_per_over as f64 / balls_bowled as f64
+    }
+
+    pub fn required_run_rate(&self) -> Option<f64> {
+        let runs_needed = self.runs_needed()?;
+        let balls_remaining = self.balls_remaining()?;
+
+        if balls_remaining == 0 {
+            return None;
+        }
+
+        Some(runs_needed as f64 * self.match_config.balls_per_over as f64 / balls_remaining as f64)
+    }
+
+    fn is_end_innings(&self) -> bool {
+        let team = self.batting_team();
+
+        if team.wickets >= self.match_config.wickets_per_innings {
+            return true;
+        }
+
+        if let Some(overs_per_innings) = self.match_config.overs_per_innings {
+            if team.overs.overs >= overs_per_innings {
+                return true;
+            }
+        }
+
+        match self.target() {
+            Some(target) => team.runs >= target,
+            None => false,
+        }
+    }
+
+    fn end_ball(&mut self) -> Option<Page> {
+        if self.is_end_over() {
+            self.end_over();
+        }
+
+        if self.is_end_innings() {
+            self.end_innings(InningsStatus::Completed);
+        }
+
+        self.next_page()
+    }
+
+    pub fn next_page(&self) -> Option<Page> {
+        if self.is_match_over() {
+            return Some(Page::Result);
+        }
+
+        if !self.innings_in_progress {
+            return Some(Page::SelectInnings);
+        }
+
+        if self.batter_to_replace().is_some() {
+            return Some(Page::SelectBatter);
+        }
+
+        if self.bowler.is_none() {
+            return Some(Page::SelectBowler);
+        }
+
+        None
+    }
+
+    fn end_innings(&mut self, status: InningsStatus) {
+        let mut innings = Innings::new(
+            self.innings_completed(),
+            self.batting_team(),
+            self.bowling_team(),
+            self.follow_on,
+        );
+        innings.status = status;
+        self.add_event(GameEvent::EndInnings(innings.summary()));
+        self.batting_team_mut().innings.push(innings);
+
+        // the fielding side keeps any penalty runs awarded before it has batted
+        self.batting_team_mut().reset_innings();
+        self.bowling_team_mut().reset_players();
+        self.follow_on = false;
+        self.free_hit = false;
+        self.innings_in_progress = false;
+
+        self.batter_a = None;
+        self.batter_b = None;
+        self.on_strike_batter = PlayerType::A;
+        self.bowler = None;
+        self.last_bowler = None;
+        self.last_last_bowler = None;
+        self.replaced_bowlers.clear();
+        self.last_replaced_bowlers.clear();
+
+        if self.innings_completed() >= self.match_config.total_innings()
+            || self.innings_victory().is_some()
+        {
+            self.end_match();
+            return;
+        }
+
+        if !self.is_follow_on_available() {
+            self.batting_team = self.next_batting_team();
+            self.innings_in_progress = true;
+        }
+    }
+
+    fn next_batting_team(&self) -> TeamType {
+        let innings_a = self.team_a.innings.len();
+        let innings_b = self.team_b.innings.len();
+
+        if innings_a < innings_b {
+            TeamType::A
+        } else if innings_b < innings_a {
+            TeamType::B
+        } else {
+            self.first_innings_team().unwrap_or(TeamType::A)
+        }
+    }
+
+    fn innings_victory(&self) -> Option<MatchResult> {
+        for team_type in [TeamType::A, TeamType::B] {
+            let team = self.team(&team_type);
+            let other_team = self.other_team(&team_type);
+
+            // the other team has an innings in hand that it doesn't need to bat
+            if team.innings.len() == self.match_config.innings_per_side as usize
+                && !other_team.innings.is_empty()
+                && other_team.innings.len() < team.innings.len()
+                && team.total_runs() < other_team.total_runs()
+            {
+                let winning_team = match team_type {
+                    TeamType::A => TeamType::B,
+                    TeamType::B => TeamType::A,
+                };
+
+                return Some(MatchResult::WonByInnings {
+                    team: winning_team,
+                    runs: other_team.total_runs() - team.total_runs(),
+                });
+            }
+        }
+
+        None
+    }
+
+    fn end_match(&mut self) {
+        let result = self.calculate_result();
+        self.add_event(GameEvent::MatchResult(result.clone()));
+        self.result = Some(result);
+    }
+
+    fn calculate_result(&self) -> MatchResult {
+        if let Some(result) = self.innings_victory() {
+            return result;
+        }
+
+        if self.innings_completed() < self.match_config.total_innings() {
+            return match self.match_config.overs_per_innings {
+                Some(_) => MatchResult::NoResult,
+                None => MatchResult::Draw,
+            };
+        }
+
+        let chasing_team_type = self
+            .last_innings_team()
+            .expect("The final innings should have been played");
+        let chasing_team = self.team(&chasing_team_type);
+        let defending_team = self.other_team(&chasing_team_type);
+        let defending_runs = defending_team.total_runs();
+        let chasing_runs = chasing_team.total_runs();
+
+        if chasing_runs > defending_runs {
+            let chasing_innings = chasing_team
+                .innings
+                .last()
+                .expect("The chasing team should have batted");
+
+            MatchResult::WonByWickets {
+                team: chasing_team_type,
+                wickets: self.match_config.wickets_per_innings - chasing_innings.wickets,
+                balls_remaining: self.match_config.balls_per_innings().map(|balls| {
+                    balls
+                        - chasing_innings
+                            .overs
+                            .total_balls(self.match_config.balls_per_over)
+                }),
+            }
+        } else if chasing_runs < defending_runs {
+            MatchResult::WonByRuns {
+                team: match chasing_team_type {
+                    TeamType::A => TeamType::B,
+                    TeamType::B => TeamType::A,
+                },
+                runs: defending_runs - chasing_runs,
+            }
+        } else {
+            MatchResult::Tie
+        }
+    }
+
+    fn is_end_over(&self) -> bool {
+        self.batting_team().overs.balls >= self.match_config.balls_per_over
+    }
+
+    fn end_over(&mut self) {
+        let bowler = Rc::clone(self.bowler.as_ref().expect("There should be a bowler"));
+        let mut bowler = bowler.borrow_mut();
+
+        // an over shared between bowlers is not a maiden for any of them
+        if self.replaced_bowlers.is_empty() && bowler.runs_conceded == self.over_start_runs_conceded
+        {
+            bowler.maidens += 1;
+        }
+
+        self.last_last_bowler = self.last_bowler;
+        self.last_bowler = Some(bowler.order);
+        self.last_replaced_bowlers = std::mem::take(&mut self.replaced_bowlers);
+        self.bowler = None;
+        drop(bowler);
+
+        self.batting_team_mut().overs.end_over();
+        self.change_strike();
+
+        let team = self.batting_team();
         self.add_event(GameEvent::EndOver(Summary::new(
             team.runs,
             team.wickets,
@@ -490,7 +1544,672 @@ impl GameState {
     }
 }
 
+pub enum BowlerRestriction {
+    BowlingThisOver,
+    BowledLastOver,
+    QuotaUsed(u32),
+}
+
+impl std::fmt::Display for BowlerRestriction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BowlingThisOver => write!(f, "has bowled in this over"),
+            Self::BowledLastOver => write!(f, "bowled the last over"),
+            Self::QuotaUsed(overs) => write!(f, "has bowled their {overs} overs"),
+        }
+    }
+}
+
 pub enum ReplaceBatter {
     OnStrike,
     OffStrike,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use extras::{ByeType, PenaltyReason};
+    use wickets::RunOut;
+
+    fn setup(match_config: MatchConfig) -> Vec<GameEvent> {
+        let mut events = vec![GameEvent::StartGame(match_config.clone())];
+
+        for team_name in ["A", "B"] {
+            for order in 0..match_config.players_per_side {
+                let player = Player::new(team_name, &order.to_string(), order);
+                events.push(GameEvent::AddPlayer(player));
+            }
+            events.push(GameEvent::SubmitTeam(String::from(team_name)));
+        }
+
+        events
+    }
+
+    fn start_innings(team: TeamType) -> Vec<GameEvent> {
+        vec![
+            GameEvent::StartInnings(team),
+            GameEvent::SelectOnStrike(0),
+            GameEvent::SelectOffStrike(1),
+            GameEvent::SelectBowler(2),
+        ]
+    }
+
+    fn runs(runs: u32) -> GameEvent {
+        GameEvent::Runs(Runs::new(runs))
+    }
+
+    fn bowled() -> GameEvent {
+        GameEvent::Wicket(WicketEvent::new(HowOut::Bowled, Some(2), None))
+    }
+
+    // scores the runs then loses both wickets
+    fn all_out(innings_runs: u32) -> Vec<GameEvent> {
+        vec![
+            runs(innings_runs),
+            bowled(),
+            GameEvent::SelectOnStrike(2),
+            bowled(),
+        ]
+    }
+
+    fn innings(team: TeamType, innings_runs: u32) -> Vec<GameEvent> {
+        let mut events = start_innings(team);
+        events.extend(all_out(innings_runs));
+
+        events
+    }
+
+    fn player(game_state: &GameState, team: TeamType, order: usize) -> Player {
+        game_state.team(&team).players[order].borrow().clone()
+    }
+
+    fn play(match_config: MatchConfig, innings: Vec<Vec<GameEvent>>) -> GameState {
+        let mut events = setup(match_config);
+        events.extend(innings.into_iter().flatten());
+
+        GameState::from_events(events)
+    }
+
+    fn one_over_match() -> MatchConfig {
+        MatchConfig::new(Some(1), 6, 3, 2)
+    }
+
+    fn five_over_match() -> MatchConfig {
+        MatchConfig::new(Some(5), 6, 3, 2)
+    }
+
+    fn two_innings_match() -> MatchConfig {
+        MatchConfig::new(None, 6, 3, 2).with_innings(2, 5)
+    }
+
+    #[test]
+    fn defending_team_wins_by_runs() {
+        let game_state = play(
+            one_over_match(),
+            vec![innings(TeamType::A, 4), innings(TeamType::B, 1)],
+        );
+
+        assert_eq!(
+            game_state.result,
+            Some(MatchResult::WonByRuns {
+                team: TeamType::A,
+                runs: 3
+            })
+        );
+    }
+
+    #[test]
+    fn chasing_team_wins_by_wickets() {
+        let mut chase = start_innings(TeamType::B);
+        chase.push(runs(3));
+
+        let game_state = play(one_over_match(), vec![innings(TeamType::A, 2), chase]);
+
+        assert_eq!(
+            game_state.result,
+            Some(MatchResult::WonByWickets {
+                team: TeamType::B,
+                wickets: 2,
+                balls_remaining: Some(5)
+            })
+        );
+    }
+
+    #[test]
+    fn equal_scores_tie() {
+        let game_state = play(
+            one_over_match(),
+            vec![innings(TeamType::A, 2), innings(TeamType::B, 2)],
+        );
+
+        assert_eq!(game_state.result, Some(MatchResult::Tie));
+    }
+
+    #[test]
+    fn follow_on_available_with_a_big_enough_lead() {
+        let game_state = play(
+            two_innings_match(),
+            vec![innings(TeamType::A, 6), innings(TeamType::B, 0)],
+        );
+        assert!(game_state.is_follow_on_available());
+
+        let game_state = play(
+            two_innings_match(),
+            vec![innings(TeamType::A, 4), innings(TeamType::B, 0)],
+        );
+        assert!(!game_state.is_follow_on_available());
+    }
+
+    #[test]
+    fn innings_victory_after_the_follow_on() {
+        let game_state = play(
+            two_innings_match(),
+            vec![
+                innings(TeamType::A, 6),
+                innings(TeamType::B, 0),
+                innings(TeamType::B, 2),
+            ],
+        );
+
+        assert_eq!(
+            game_state.result,
+            Some(MatchResult::WonByInnings {
+                team: TeamType::A,
+                runs: 4
+            })
+        );
+    }
+
+    #[test]
+    fn innings_only_declared_or_forfeited_before_the_final_innings_of_timed_matches() {
+        let forfeit = vec![
+            GameEvent::StartInnings(TeamType::A),
+            GameEvent::ForfeitInnings,
+        ];
+
+        let game_state = play(one_over_match(), vec![forfeit.clone()]);
+        assert_eq!(game_state.invalid_events.len(), 1);
+        assert!(game_state.innings_in_progress);
+
+        let game_state = play(two_innings_match(), vec![forfeit]);
+        assert!(game_state.invalid_events.is_empty());
+        assert_eq!(
+            game_state.team_a.innings[0].status,
+            InningsStatus::Forfeited
+        );
+
+        let mut declaration = start_innings(TeamType::B);
+        declaration.push(GameEvent::Declare);
+
+        let game_state = play(
+            two_innings_match(),
+            vec![
+                innings(TeamType::A, 2),
+                innings(TeamType::B, 3),
+                innings(TeamType::A, 2),
+                declaration,
+            ],
+        );
+        assert_eq!(game_state.invalid_events.len(), 1);
+        assert!(game_state.innings_in_progress);
+    }
+
+    #[test]
+    fn runs_off_the_bat_on_a_no_ball_count_to_the_batter() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Extra(Extra::new(2, ExtraType::NoBall)));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let batter = player(&game_state, TeamType::A, 0);
+        let bowler = player(&game_state, TeamType::B, 2);
+
+        assert_eq!(game_state.batting_team().runs, 3);
+        assert_eq!(game_state.batting_team().overs, Overs::new());
+        assert_eq!((batter.runs_scored, batter.balls_faced), (2, 1));
+        assert_eq!(bowler.runs_conceded, 3);
+    }
+
+    #[test]
+    fn byes_on_a_no_ball_are_not_credited_to_the_batter_or_bowler() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Extra(
+            Extra::new(3, ExtraType::NoBall).with_byes(ByeType::LegBye),
+        ));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let batter = player(&game_state, TeamType::A, 0);
+        let bowler = player(&game_state, TeamType::B, 2);
+
+        assert_eq!(game_state.batting_team().runs, 4);
+        assert_eq!((batter.runs_scored, batter.balls_faced), (0, 1));
+        assert_eq!(bowler.runs_conceded, 1);
+        // three runs were run, so the batters changed ends
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 1);
+    }
+
+    fn penalty(team: TeamType, runs: u32) -> GameEvent {
+        GameEvent::PenaltyRuns(Penalty::new(team, runs, PenaltyReason::Other))
+    }
+
+    #[test]
+    fn penalty_runs_for_the_batting_side_are_not_credited_to_the_bowler() {
+        let mut events = start_innings(TeamType::A);
+        events.push(penalty(TeamType::A, 5));
+
+        let game_state = play(one_over_match(), vec![events]);
+
+        assert_eq!(game_state.batting_team().runs, 5);
+        assert_eq!(player(&game_state, TeamType::A, 0).runs_scored, 0);
+        assert_eq!(player(&game_state, TeamType::B, 2).runs_conceded, 0);
+    }
+
+    #[test]
+    fn penalty_runs_for_the_fielding_side_go_to_its_last_or_next_innings() {
+        // before the fielding side has batted, it starts its innings with them
+        let mut first_innings = start_innings(TeamType::A);
+        first_innings.push(penalty(TeamType::B, 5));
+        first_innings.extend(all_out(0));
+
+        let game_state = play(
+            two_innings_match(),
+            vec![first_innings, vec![GameEvent::StartInnings(TeamType::B)]],
+        );
+        assert_eq!(game_state.batting_team().runs, 5);
+
+        // after it has batted, they are added to its last innings
+        let mut second_innings = start_innings(TeamType::B);
+        second_innings.push(penalty(TeamType::A, 5));
+
+        let game_state = play(
+            one_over_match(),
+            vec![innings(TeamType::A, 4), second_innings],
+        );
+        assert_eq!(game_state.team_a.total_runs(), 9);
+        assert_eq!(game_state.target(), Some(10));
+    }
+
+    #[test]
+    fn not_out_batter_on_strike_after_striker_run_out_at_other_end() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Wicket(
+            WicketEvent::new(HowOut::RunOut, None, None).with_run_out(RunOut::new(
+                0,
+                PlayerType::A,
+                CreaseEnd::NonStriker,
+            )),
+        ));
+
+        let game_state = play(one_over_match(), vec![events.clone()]);
+        assert!(matches!(
+            game_state.batter_to_replace(),
+            Some(ReplaceBatter::OffStrike)
+        ));
+
+        events.push(GameEvent::SelectOffStrike(2));
+        let game_state = play(one_over_match(), vec![events]);
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 1);
+    }
+
+    #[test]
+    fn new_batter_on_strike_after_non_striker_run_out_at_striker_end() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Wicket(
+            WicketEvent::new(HowOut::RunOut, None, None).with_run_out(RunOut::new(
+                1,
+                PlayerType::B,
+                CreaseEnd::Striker,
+            )),
+        ));
+
+        let game_state = play(one_over_match(), vec![events.clone()]);
+        assert!(matches!(
+            game_state.batter_to_replace(),
+            Some(ReplaceBatter::OnStrike)
+        ));
+
+        events.push(GameEvent::SelectOnStrike(2));
+        let game_state = play(one_over_match(), vec![events]);
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 2);
+
+        // the run completed before the wicket still counts to the striker
+        assert_eq!(game_state.batting_team().runs, 1);
+        assert_eq!(player(&game_state, TeamType::A, 0).runs_scored, 1);
+    }
+
+    #[test]
+    fn stumping_off_a_wide_is_not_a_ball_but_credits_the_bowler() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Wicket(
+            WicketEvent::new(HowOut::Stumped, Some(2), Some(0))
+                .with_extra(Extra::new(0, ExtraType::Wide)),
+        ));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let bowler = player(&game_state, TeamType::B, 2);
+
+        assert_eq!(game_state.batting_team().runs, 1);
+        assert_eq!(game_state.batting_team().wickets, 1);
+        assert_eq!(game_state.batting_team().overs, Overs::new());
+        assert_eq!(player(&game_state, TeamType::A, 0).balls_faced, 0);
+        assert_eq!((bowler.wickets_taken, bowler.runs_conceded), (1, 1));
+    }
+
+    #[test]
+    fn runs_completed_before_a_run_out_can_be_byes() {
+        let run_out = WicketEvent::new(HowOut::RunOut, None, Some(0)).with_run_out(RunOut::new(
+            1,
+            PlayerType::A,
+            CreaseEnd::NonStriker,
+        ));
+
+        // off a no ball the bye is added to the no ball extra
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Wicket(run_out.clone().with_extra(
+            Extra::new(1, ExtraType::NoBall).with_byes(ByeType::Bye),
+        )));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let batter = player(&game_state, TeamType::A, 0);
+
+        assert_eq!(game_state.batting_team().runs, 2);
+        assert_eq!(game_state.batting_team().overs, Overs::new());
+        assert_eq!((batter.runs_scored, batter.balls_faced), (0, 1));
+        assert_eq!(player(&game_state, TeamType::B, 2).runs_conceded, 1);
+
+        // off a legal ball they are byes, and the ball still counts
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Wicket(
+            run_out.with_extra(Extra::new(1, ExtraType::Bye)),
+        ));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let batter = player(&game_state, TeamType::A, 0);
+
+        assert_eq!(game_state.batting_team().runs, 1);
+        assert_eq!(game_state.batting_team().overs.balls, 1);
+        assert_eq!((batter.runs_scored, batter.balls_faced), (0, 1));
+        assert_eq!(player(&game_state, TeamType::B, 2).runs_conceded, 0);
+    }
+
+    #[test]
+    fn only_boundaries_hit_by_the_batter_count_as_fours_and_sixes() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Runs(Runs::boundary(Boundary::Four)));
+        events.push(runs(4));
+        events.push(GameEvent::Extra(
+            Extra::new(0, ExtraType::NoBall).with_boundary(Boundary::Six),
+        ));
+        events.push(GameEvent::Extra(
+            Extra::new(0, ExtraType::NoBall)
+                .with_byes(ByeType::Bye)
+                .with_boundary(Boundary::Four),
+        ));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let batter = player(&game_state, TeamType::A, 0);
+
+        assert_eq!(game_state.batting_team().runs, 4 + 4 + 7 + 5);
+        assert_eq!(batter.runs_scored, 4 + 4 + 6);
+        assert_eq!((batter.fours, batter.sixes), (1, 1));
+    }
+
+    #[test]
+    fn boundary_percentage_is_of_runs_off_the_bat() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Extra(
+            Extra::new(0, ExtraType::NoBall).with_boundary(Boundary::Six),
+        ));
+        events.push(runs(2));
+        events.push(penalty(TeamType::A, 5));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let (_, innings) = game_state.all_innings().pop().unwrap();
+
+        assert_eq!(innings.runs, 14);
+        assert_eq!(innings.boundary_percentage(), Some(75.0));
+    }
+
+    #[test]
+    fn overthrows_count_to_the_batter_and_change_ends_when_run() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Runs(Runs::new(1).with_overthrows(2)));
+
+        let game_state = play(one_over_match(), vec![events.clone()]);
+
+        assert_eq!(game_state.batting_team().runs, 3);
+        assert_eq!(player(&game_state, TeamType::A, 0).runs_scored, 3);
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 1);
+
+        // overthrows to the rope add four but are not a four hit
+        events.push(GameEvent::Runs(
+            Runs::new(1).with_boundary(Boundary::Overthrows),
+        ));
+
+        let game_state = play(one_over_match(), vec![events]);
+        let batter = player(&game_state, TeamType::A, 1);
+
+        assert_eq!(batter.runs_scored, 5);
+        assert_eq!(batter.fours, 0);
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 0);
+    }
+
+    #[test]
+    fn short_runs_are_not_scored_but_still_change_ends() {
+        let mut events = start_innings(TeamType::A);
+        events.push(GameEvent::Runs(Runs::new(3).with_short_runs(1)));
+
+        let game_state = play(one_over_match(), vec![events]);
+
+        assert_eq!(game_state.batting_team().runs, 2);
+        assert_eq!(player(&game_state, TeamType::A, 0).runs_scored, 2);
+        assert_eq!(player(&game_state, TeamType::B, 2).runs_conceded, 2);
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 1);
+    }
+
+    fn over(balls: usize) -> Vec<GameEvent> {
+        vec![runs(0); balls]
+    }
+
+    #[test]
+    fn bowlers_cannot_bowl_consecutive_overs() {
+        let mut events = start_innings(TeamType::A);
+        events.extend(over(6));
+
+        let game_state = play(five_over_match(), vec![events]);
+
+        assert!(matches!(
+            game_state.bowler_restriction(2),
+            Some(BowlerRestriction::BowledLastOver)
+        ));
+        assert!(game_state.bowler_restriction(1).is_none());
+    }
+
+    #[test]
+    fn partial_overs_count_towards_the_quota() {
+        let match_config = five_over_match().with_max_overs_per_bowler(Some(1));
+
+        // bowler 2 bowls half an over before being replaced by bowler 1
+        let mut events = start_innings(TeamType::A);
+        events.extend(over(3));
+        events.push(GameEvent::ReplaceBowler(1));
+        events.extend(over(3));
+        events.push(GameEvent::SelectBowler(0));
+        events.extend(over(6));
+
+        let game_state = play(match_config, vec![events]);
+
+        // half an over is left, which isn't room for another over
+        for bowler in [1, 2] {
+            assert!(matches!(
+                game_state.bowler_restriction(bowler),
+                Some(BowlerRestriction::QuotaUsed(1))
+            ));
+        }
+    }
+
+    #[test]
+    fn every_bowler_replaced_in_an_over_is_restricted() {
+        let match_config = MatchConfig::new(Some(5), 6, 4, 3);
+
+        // bowler 2 is replaced by 3, who is replaced by 0
+        let mut events = start_innings(TeamType::A);
+        events.extend(over(2));
+        events.push(GameEvent::ReplaceBowler(3));
+        events.extend(over(2));
+        events.push(GameEvent::ReplaceBowler(0));
+        events.extend(over(1));
+
+        let game_state = play(match_config.clone(), vec![events.clone()]);
+
+        for bowler in [0, 2, 3] {
+            assert!(matches!(
+                game_state.bowler_restriction(bowler),
+                Some(BowlerRestriction::BowlingThisOver)
+            ));
+        }
+
+        events.extend(over(1));
+        let game_state = play(match_config, vec![events]);
+
+        for bowler in [0, 2, 3] {
+            assert!(matches!(
+                game_state.bowler_restriction(bowler),
+                Some(BowlerRestriction::BowledLastOver)
+            ));
+        }
+        assert!(game_state.bowler_restriction(1).is_none());
+    }
+
+    #[test]
+    fn shared_over_is_not_a_maiden() {
+        let mut events = start_innings(TeamType::A);
+        events.extend(over(3));
+        events.push(GameEvent::ReplaceBowler(1));
+        events.extend(over(3));
+
+        let game_state = play(five_over_match(), vec![events]);
+
+        for bowler in [1, 2] {
+            assert_eq!(player(&game_state, TeamType::B, bowler).maidens, 0);
+        }
+    }
+
+    fn correct(batter: PlayerType, player: usize) -> GameEvent {
+        GameEvent::CorrectBatter(BatterCorrection::new(batter, player))
+    }
+
+    #[test]
+    fn batter_correction_moves_the_innings_to_the_player_at_the_crease() {
+        let mut events = start_innings(TeamType::A);
+        events.push(runs(4));
+        events.push(correct(PlayerType::A, 2));
+
+        let game_state = play(five_over_match(), vec![events]);
+        let entered = player(&game_state, TeamType::A, 0);
+        let batter = player(&game_state, TeamType::A, 2);
+
+        assert!(game_state.invalid_events.is_empty());
+        assert_eq!(entered.how_out, HowOut::DidNotBat);
+        assert_eq!((batter.runs_scored, batter.balls_faced), (4, 1));
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 2);
+    }
+
+    #[test]
+    fn batter_correction_to_a_missing_or_finished_batter_is_invalid() {
+        let mut events = start_innings(TeamType::A);
+        events.push(runs(4));
+        events.push(bowled());
+        events.push(GameEvent::SelectOnStrike(2));
+        events.push(correct(PlayerType::A, 9));
+        events.push(correct(PlayerType::A, 0));
+
+        let match_config = MatchConfig::new(Some(5), 6, 4, 3);
+        let game_state = play(match_config, vec![events]);
+        let out_batter = player(&game_state, TeamType::A, 0);
+
+        assert_eq!(game_state.invalid_events.len(), 2);
+        assert_eq!(out_batter.how_out, HowOut::Bowled);
+        assert_eq!(out_batter.runs_scored, 4);
+        assert_eq!(game_state.on_strike_batter().unwrap().borrow().order, 2);
+    }
+
+    // starts correcting the nth delivery of the log
+    fn correction(game_state: &GameState, delivery: usize, insert: bool) -> GameState {
+        let event_index = game_state
+            .events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| event.is_ball())
+            .nth(delivery)
+            .map(|(event_index, _)| event_index)
+            .unwrap();
+
+        GameState::for_correction(EventCorrection::new(
+            game_state.events.clone(),
+            event_index,
+            insert,
+        ))
+    }
+
+    #[test]
+    fn edited_and_inserted_deliveries_replay_the_rest_of_the_log() {
+        let mut events = start_innings(TeamType::A);
+        events.push(runs(1));
+        events.push(runs(2));
+
+        let game_state = play(five_over_match(), vec![events]);
+
+        let mut edited = correction(&game_state, 0, false);
+        edited.update(runs(3));
+        assert_eq!(edited.batting_team().runs, 5);
+        assert_eq!(edited.batting_team().overs.balls, 2);
+        assert!(edited.correction.is_none());
+
+        let mut inserted = correction(&game_state, 0, true);
+        inserted.update(runs(4));
+        assert_eq!(inserted.batting_team().runs, 7);
+        assert_eq!(inserted.batting_team().overs.balls, 3);
+    }
+
+    #[test]
+    fn corrected_wicket_asks_for_the_new_batter_before_the_rest_of_the_log() {
+        let mut events = start_innings(TeamType::A);
+        events.push(runs(0));
+        events.push(runs(2));
+
+        let game_state = play(five_over_match(), vec![events]);
+        let mut game_state = correction(&game_state, 0, false);
+
+        assert!(matches!(
+            game_state.update(bowled()),
+            Some(Page::SelectBatter)
+        ));
+        assert!(game_state
+            .correction
+            .as_ref()
+            .is_some_and(|correction| correction.insert));
+
+        assert!(matches!(
+            game_state.update(GameEvent::SelectOnStrike(2)),
+            Some(Page::Scoring)
+        ));
+        assert!(game_state.correction.is_none());
+        assert!(game_state.invalid_events.is_empty());
+        assert_eq!(game_state.batting_team().wickets, 1);
+        assert_eq!(player(&game_state, TeamType::A, 2).runs_scored, 2);
+    }
+
+    #[test]
+    fn events_left_with_nothing_to_apply_to_are_kept_as_invalid() {
+        let mut events = start_innings(TeamType::A);
+        events.push(bowled());
+        events.push(runs(2));
+        events.push(GameEvent::SelectOnStrike(9));
+
+        let game_state = play(five_over_match(), vec![events]);
+
+        assert_eq!(game_state.invalid_events.len(), 2);
+        assert_eq!(game_state.batting_team().runs, 0);
+        assert!(matches!(game_state.next_page(), Some(Page::SelectBatter)));
+    }
+}
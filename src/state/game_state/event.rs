@@ -1,9 +1,14 @@
 use crate::components::event_edit::EventEdit;
-use crate::state::game_state::extras::Extra;
+use crate::state::game_state::extras::{Extra, Penalty};
+use crate::state::game_state::match_config::MatchConfig;
+use crate::state::game_state::match_result::MatchResult;
+use crate::state::game_state::runs;
+use crate::state::game_state::runs::Runs;
+use crate::state::game_state::substitutions::Substitution;
 use crate::state::game_state::summary::Summary;
-use crate::state::game_state::wickets::WicketEvent;
+use crate::state::game_state::wickets::{Retirement, WicketEvent};
 use crate::state::game_state::Event;
-use crate::state::game_state::{Player, TeamType};
+use crate::state::game_state::{BatterCorrection, Player, TeamType};
 use iced::widget::{container, row, text, Container};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
@@ -11,37 +16,87 @@ use std::rc::Rc;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum GameEvent {
-    Runs(u32),
+    Runs(#[serde(deserialize_with = "runs::deserialize_saved")] Runs),
     Extra(Extra),
     Wicket(WicketEvent),
     StartOver,
     EndOver(Summary),
     StartInnings(TeamType),
-    EndInnings,
+    EndInnings(Summary),
     SelectOnStrike(usize),
     SelectOffStrike(usize),
     SelectBowler(usize),
     AddPlayer(Player),
     SubmitTeam(String),
+    StartGame(MatchConfig),
+    EndMatch,
+    MatchResult(MatchResult),
+    Declare,
+    ForfeitInnings,
+    PenaltyRuns(Penalty),
+    Retire(Retirement),
+    ChangeKeeper(usize),
+    ChangeCaptain(usize),
+    ReplaceBowler(usize),
+    Substitute(Substitution),
+    SwapStrike,
+    CorrectBatter(BatterCorrection),
 }
 
 impl GameEvent {
     pub fn to_container(&self) -> Option<Container<Event>> {
         let container_text = match self {
-            Self::Runs(runs) => format!(
-                "{runs} run{plural}",
-                plural = if *runs == 1 { "" } else { "s" }
-            ),
+            Self::Runs(runs) => runs.to_string(),
             Self::Wicket(wicket_event) => {
-                String::from(format!("wicket: {}", wicket_event.how_out.to_string()))
+                let wicket_text = match &wicket_event.run_out {
+                    Some(run_out) if wicket_event.runs_completed() > 0 => format!(
+                        "wicket: {how_out} ({end}) after {runs} run{plural}",
+                        how_out = wicket_event.how_out,
+                        end = run_out.end.to_string().to_lowercase(),
+                        runs = run_out.runs_completed,
+                        plural = if run_out.runs_completed == 1 { "" } else { "s" }
+                    ),
+                    Some(run_out) => format!(
+                        "wicket: {how_out} ({end})",
+                        how_out = wicket_event.how_out,
+                        end = run_out.end.to_string().to_lowercase()
+                    ),
+                    None => String::from(format!("wicket: {}", wicket_event.how_out.to_string())),
+                };
+
+                match &wicket_event.extra {
+                    Some(extra) => format!("{wicket_text}, {}", extra.to_string().to_lowercase()),
+                    None => wicket_text,
+                }
             }
-            Self::Extra(extra) => String::from(format!("extra: {}", extra.extra_type.to_string())),
+            Self::Extra(extra) => format!("extra: {extra}"),
             Self::EndOver(summary) => String::from(format!(
                 "End of over: {}/{} ({})",
                 summary.wickets,
                 summary.runs,
                 summary.overs.to_string()
             )),
+            Self::PenaltyRuns(penalty) => format!(
+                "{runs} penalty run{plural}: {reason}",
+                runs = penalty.runs,
+                plural = if penalty.runs == 1 { "" } else { "s" },
+                reason = penalty.reason
+            ),
+            Self::Retire(retirement) => retirement.how_out.to_string(),
+            Self::ChangeKeeper(_) => String::from("Wicketkeeper changed"),
+            Self::ChangeCaptain(_) => String::from("Captain changed"),
+            Self::ReplaceBowler(_) => String::from("Bowler replaced mid-over"),
+            Self::Substitute(substitution) => substitution.kind.to_string(),
+            Self::SwapStrike => String::from("Strike swapped"),
+            Self::CorrectBatter(_) => String::from("Batter at the crease corrected"),
+            Self::Declare => String::from("Innings declared"),
+            Self::ForfeitInnings => String::from("Innings forfeited"),
+            Self::EndInnings(summary) => format!(
+                "End of innings: {}/{} ({})",
+                summary.wickets,
+                summary.runs,
+                summary.overs.to_string()
+            ),
             _ => return None,
         };
 
@@ -55,14 +110,73 @@ impl GameEvent {
         }
     }
 
+    pub fn is_batter_selection(&self) -> bool {
+        matches!(
+            self,
+            GameEvent::SelectOnStrike(_) | GameEvent::SelectOffStrike(_)
+        )
+    }
+
+    pub fn is_derived(&self) -> bool {
+        matches!(
+            self,
+            GameEvent::EndOver(_) | GameEvent::EndInnings(_) | GameEvent::MatchResult(_)
+        )
+    }
+
+    pub fn is_undo_point(&self) -> bool {
+        self.is_ball()
+            || matches!(
+                self,
+                GameEvent::EndMatch
+                    | GameEvent::Declare
+                    | GameEvent::ForfeitInnings
+                    | GameEvent::PenaltyRuns(_)
+                    | GameEvent::Retire(_)
+                    | GameEvent::ChangeKeeper(_)
+                    | GameEvent::ChangeCaptain(_)
+                    | GameEvent::ReplaceBowler(_)
+                    | GameEvent::Substitute(_)
+                    | GameEvent::SwapStrike
+                    | GameEvent::CorrectBatter(_)
+            )
+    }
+
     pub fn is_setup_event(&self) -> bool {
         match *self {
-            GameEvent::AddPlayer(_) | GameEvent::SubmitTeam(_) => true,
+            GameEvent::AddPlayer(_) | GameEvent::SubmitTeam(_) | GameEvent::StartGame(_) => true,
             _ => false,
         }
     }
 }
 
+// a past delivery being replaced, or a missed ball being inserted before it
+#[derive(Debug, Clone)]
+pub struct EventCorrection {
+    pub events: Vec<GameEvent>,
+    pub event_index: usize,
+    pub insert: bool,
+}
+
+impl EventCorrection {
+    pub fn new(events: Vec<GameEvent>, event_index: usize, insert: bool) -> Self {
+        Self {
+            events,
+            event_index,
+            insert,
+        }
+    }
+
+    pub fn apply(mut self, event: GameEvent) -> Vec<GameEvent> {
+        match self.insert {
+            true => self.events.insert(self.event_index, event),
+            false => self.events[self.event_index] = event,
+        }
+
+        self.events
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GameEventHistory {
     pub event_index: usize,
@@ -1,3 +1,5 @@
+use crate::state::game_state::runs::Boundary;
+use crate::state::game_state::TeamType;
 use crate::state::Event;
 use iced::widget::{container, text, Container};
 use serde::{Deserialize, Serialize};
@@ -10,6 +12,9 @@ pub struct Extras {
     byes: u32,
     leg_byes: u32,
     penalty_runs: u32,
+    // wides are counted in runs above, but bowling figures show deliveries
+    #[serde(default)]
+    wide_deliveries: u32,
 }
 
 impl Extras {
@@ -21,29 +26,51 @@ impl Extras {
             byes: 0,
             leg_byes: 0,
             penalty_runs: 0,
+            wide_deliveries: 0,
         }
     }
 
     pub fn add_extra(&mut self, extra: &Extra) {
         match extra.extra_type {
-            ExtraType::Wide => self.wides += extra.runs + 1,
+            ExtraType::Wide => {
+                self.wides += extra.bowler_runs();
+                self.wide_deliveries += 1;
+            }
             ExtraType::NoBall => self.no_balls += 1,
             ExtraType::Bye => self.byes += extra.runs,
             ExtraType::LegBye => self.leg_byes += extra.runs,
-            ExtraType::PenaltyRuns => (),
         }
+
+        match extra.byes {
+            Some(ByeType::Bye) => self.byes += extra.runs,
+            Some(ByeType::LegBye) => self.leg_byes += extra.runs,
+            None => (),
+        }
+    }
+
+    pub fn add_penalty_runs(&mut self, runs: u32) {
+        self.penalty_runs += runs;
+    }
+
+    pub fn wide_deliveries(&self) -> u32 {
+        self.wide_deliveries
+    }
+
+    pub fn no_balls(&self) -> u32 {
+        self.no_balls
     }
 }
 
 impl Extras {
     // ui
-    pub fn to_container(&self) -> Container<Event> {
+    pub fn to_container<'a>(&self) -> Container<'a, Event> {
         container(text(format!(
-            "W: {wides}, NB: {no_balls}, B: {byes}, LB: {leg_byes}",
+            "W: {wides}, NB: {no_balls}, B: {byes}, LB: {leg_byes}, P: {penalty_runs}",
             wides = self.wides,
             no_balls = self.no_balls,
             byes = self.byes,
-            leg_byes = self.leg_byes
+            leg_byes = self.leg_byes,
+            penalty_runs = self.penalty_runs
         )))
     }
 }
@@ -52,15 +79,94 @@ impl Extras {
 pub struct Extra {
     pub runs: u32,
     pub extra_type: ExtraType,
+    #[serde(default)]
+    pub byes: Option<ByeType>,
+    #[serde(default)]
+    pub boundary: Option<Boundary>,
 }
 
 impl Extra {
     pub fn new(runs: u32, extra_type: ExtraType) -> Self {
-        Self { runs, extra_type }
+        Self {
+            runs,
+            extra_type,
+            byes: None,
+            boundary: None,
+        }
+    }
+
+    // runs taken on a wide or no ball that were byes or leg byes rather than
+    // wides or runs off the bat
+    pub fn with_byes(mut self, byes: ByeType) -> Self {
+        self.byes = Some(byes);
+        self
+    }
+
+    // the runs reached the rope, e.g. a four hit off a no ball
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.runs = boundary.runs();
+        self.boundary = Some(boundary);
+        self
+    }
+
+    // a boundary hit by the batter rather than byes or wides to the rope
+    pub fn bat_boundary(&self) -> Option<&Boundary> {
+        match (&self.extra_type, &self.byes) {
+            (ExtraType::NoBall, None) => self.boundary.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn total_runs(&self) -> u32 {
+        match self.extra_type {
+            ExtraType::Wide | ExtraType::NoBall => self.runs + 1,
+            _ => self.runs,
+        }
+    }
+
+    pub fn bat_runs(&self) -> u32 {
+        match (&self.extra_type, &self.byes) {
+            (ExtraType::NoBall, None) => self.runs,
+            _ => 0,
+        }
+    }
+
+    pub fn bowler_runs(&self) -> u32 {
+        match (&self.extra_type, &self.byes) {
+            (ExtraType::Wide | ExtraType::NoBall, None) => self.runs + 1,
+            (ExtraType::Wide | ExtraType::NoBall, Some(_)) => 1,
+            _ => 0,
+        }
+    }
+
+    pub fn is_legal_ball(&self) -> bool {
+        matches!(self.extra_type, ExtraType::Bye | ExtraType::LegBye)
+    }
+}
+
+impl std::fmt::Display for Extra {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.extra_type)?;
+
+        if self.runs == 0 {
+            return Ok(());
+        }
+
+        match (&self.extra_type, &self.byes) {
+            (_, Some(byes)) => write!(
+                f,
+                " + {runs} {byes}{plural}",
+                runs = self.runs,
+                byes = byes.to_string().to_lowercase(),
+                plural = if self.runs == 1 { "" } else { "s" }
+            ),
+            (ExtraType::NoBall, None) => write!(f, " + {} off the bat", self.runs),
+            _ => write!(f, " + {}", self.runs),
+        }
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, Display, EnumIter)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Display, EnumIter)]
 pub enum ExtraType {
     Wide,
     #[strum(to_string = "No ball")]
@@ -68,6 +174,39 @@ pub enum ExtraType {
     Bye,
     #[strum(to_string = "Leg bye")]
     LegBye,
-    #[strum(to_string = "Penalty runs")]
-    PenaltyRuns,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Display, EnumIter)]
+pub enum ByeType {
+    Bye,
+    #[strum(to_string = "Leg bye")]
+    LegBye,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Penalty {
+    pub team: TeamType,
+    pub runs: u32,
+    pub reason: PenaltyReason,
+}
+
+impl Penalty {
+    pub fn new(team: TeamType, runs: u32, reason: PenaltyReason) -> Self {
+        Self { team, runs, reason }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Display, EnumIter)]
+pub enum PenaltyReason {
+    #[strum(to_string = "Ball struck fielder's helmet")]
+    HelmetStruck,
+    #[strum(to_string = "Illegal fielding")]
+    IllegalFielding,
+    #[strum(to_string = "Slow over rate")]
+    SlowOverRate,
+    #[strum(to_string = "Damaging the pitch")]
+    DamagingPitch,
+    #[strum(to_string = "Time wasting")]
+    TimeWasting,
+    Other,
 }
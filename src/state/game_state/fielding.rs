@@ -0,0 +1,44 @@
+use crate::state::Event;
+use iced::widget::{container, text, Container};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fielding {
+    pub catches: u32,
+    pub keeper_catches: u32,
+    pub stumpings: u32,
+    pub direct_hits: u32,
+    pub run_outs: u32,
+    pub run_out_assists: u32,
+}
+
+impl Fielding {
+    // logic
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn has_dismissals(&self) -> bool {
+        *self != Self::new()
+    }
+}
+
+impl Fielding {
+    // ui
+    pub fn to_container<'a>(&self, name: String) -> Container<'a, Event> {
+        let stats = [
+            (self.catches, "ct"),
+            (self.keeper_catches, "ct (wk)"),
+            (self.stumpings, "st"),
+            (self.direct_hits, "run out (direct hit)"),
+            (self.run_outs, "run out"),
+            (self.run_out_assists, "run out assist"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{count} {label}"))
+        .collect::<Vec<String>>();
+
+        container(text(format!("{name}: {}", stats.join(", "))))
+    }
+}
@@ -0,0 +1,88 @@
+use crate::state::game_state::extras::Extras;
+use crate::state::game_state::overs::Overs;
+use crate::state::game_state::partnerships::{FallOfWicket, Partnership};
+use crate::state::game_state::summary::Summary;
+use crate::state::game_state::team::player::Player;
+use crate::state::game_state::team::Team;
+
+#[derive(Debug, Clone)]
+pub struct Innings {
+    pub number: usize,
+    pub runs: u32,
+    pub wickets: u32,
+    pub overs: Overs,
+    pub extras: Extras,
+    pub batters: Vec<Player>,
+    pub bowlers: Vec<Player>,
+    pub fall_of_wickets: Vec<FallOfWicket>,
+    pub partnerships: Vec<Partnership>,
+    pub follow_on: bool,
+    pub status: InningsStatus,
+}
+
+impl Innings {
+    pub fn new(number: usize, batting_team: &Team, bowling_team: &Team, follow_on: bool) -> Self {
+        Innings {
+            number,
+            runs: batting_team.runs,
+            wickets: batting_team.wickets,
+            overs: batting_team.overs.clone(),
+            extras: batting_team.extras.clone(),
+            batters: batting_team
+                .players
+                .iter()
+                .map(|player| player.borrow().clone())
+                .collect(),
+            bowlers: bowling_team
+                .players
+                .iter()
+                .map(|player| player.borrow().clone())
+                .collect(),
+            fall_of_wickets: batting_team.fall_of_wickets.clone(),
+            partnerships: batting_team.partnerships.clone(),
+            follow_on,
+            status: InningsStatus::InProgress,
+        }
+    }
+
+    pub fn add_penalty_runs(&mut self, runs: u32) {
+        self.runs += runs;
+        self.extras.add_penalty_runs(runs);
+    }
+
+    pub fn batter(&self, order: usize) -> Option<&Player> {
+        self.batters.iter().find(|batter| batter.order == order)
+    }
+
+    pub fn fours(&self) -> u32 {
+        self.batters.iter().map(|batter| batter.fours).sum()
+    }
+
+    pub fn sixes(&self) -> u32 {
+        self.batters.iter().map(|batter| batter.sixes).sum()
+    }
+
+    // share of the runs off the bat that came from boundaries
+    pub fn boundary_percentage(&self) -> Option<f64> {
+        let bat_runs: u32 = self.batters.iter().map(|batter| batter.runs_scored).sum();
+
+        if bat_runs == 0 {
+            return None;
+        }
+
+        let boundary_runs = self.fours() * 4 + self.sixes() * 6;
+        Some(boundary_runs as f64 * 100.0 / bat_runs as f64)
+    }
+
+    pub fn summary(&self) -> Summary {
+        Summary::new(self.runs, self.wickets, self.overs.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InningsStatus {
+    InProgress,
+    Completed,
+    Declared,
+    Forfeited,
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchConfig {
+    pub overs_per_innings: Option<u32>,
+    pub balls_per_over: u32,
+    pub players_per_side: usize,
+    pub wickets_per_innings: u32,
+    pub innings_per_side: u32,
+    pub follow_on_margin: u32,
+    pub free_hits: bool,
+    pub max_overs_per_bowler: Option<u32>,
+}
+
+impl MatchConfig {
+    pub fn new(
+        overs_per_innings: Option<u32>,
+        balls_per_over: u32,
+        players_per_side: usize,
+        wickets_per_innings: u32,
+    ) -> Self {
+        Self {
+            overs_per_innings,
+            balls_per_over,
+            players_per_side,
+            wickets_per_innings,
+            innings_per_side: 1,
+            follow_on_margin: 150,
+            free_hits: false,
+            max_overs_per_bowler: None,
+        }
+    }
+
+    pub fn with_max_overs_per_bowler(mut self, max_overs_per_bowler: Option<u32>) -> Self {
+        self.max_overs_per_bowler = max_overs_per_bowler;
+        self
+    }
+
+    pub fn with_free_hits(mut self, free_hits: bool) -> Self {
+        self.free_hits = free_hits;
+        self
+    }
+
+    pub fn with_innings(mut self, innings_per_side: u32, follow_on_margin: u32) -> Self {
+        self.innings_per_side = innings_per_side;
+        self.follow_on_margin = follow_on_margin;
+        self
+    }
+
+    pub fn total_innings(&self) -> usize {
+        (self.innings_per_side * 2) as usize
+    }
+
+    pub fn balls_per_innings(&self) -> Option<u32> {
+        Some(self.overs_per_innings? * self.balls_per_over)
+    }
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        Self::new(None, 6, 11, 10)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Display, EnumIter)]
+pub enum MatchFormat {
+    #[strum(to_string = "T20")]
+    T20,
+    #[strum(to_string = "40 overs")]
+    FortyOvers,
+    #[strum(to_string = "Eight-a-side")]
+    EightASide,
+    #[strum(to_string = "100 balls")]
+    HundredBalls,
+    #[strum(to_string = "Two-day (two innings)")]
+    TwoDay,
+    Custom,
+}
+
+impl MatchFormat {
+    pub fn config(&self) -> MatchConfig {
+        match self {
+            MatchFormat::T20 => MatchConfig::new(Some(20), 6, 11, 10)
+                .with_free_hits(true)
+                .with_max_overs_per_bowler(Some(4)),
+            MatchFormat::FortyOvers => MatchConfig::new(Some(40), 6, 11, 10)
+                .with_free_hits(true)
+                .with_max_overs_per_bowler(Some(8)),
+            MatchFormat::EightASide => MatchConfig::new(Some(12), 6, 8, 7)
+                .with_free_hits(true)
+                .with_max_overs_per_bowler(Some(3)),
+            MatchFormat::HundredBalls => MatchConfig::new(Some(20), 5, 11, 10)
+                .with_free_hits(true)
+                .with_max_overs_per_bowler(Some(4)),
+            MatchFormat::TwoDay => MatchConfig::new(None, 6, 11, 10).with_innings(2, 100),
+            MatchFormat::Custom => MatchConfig::default(),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::state::game_state::{GameState, TeamType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MatchResult {
+    WonByRuns {
+        team: TeamType,
+        runs: u32,
+    },
+    WonByWickets {
+        team: TeamType,
+        wickets: u32,
+        balls_remaining: Option<u32>,
+    },
+    WonByInnings {
+        team: TeamType,
+        runs: u32,
+    },
+    Tie,
+    NoResult,
+    Draw,
+}
+
+impl MatchResult {
+    pub fn describe(&self, game_state: &GameState) -> String {
+        match self {
+            MatchResult::WonByRuns { team, runs } => format!(
+                "{team_name} won by {runs} run{plural}",
+                team_name = game_state.team(team).team_name,
+                plural = if *runs == 1 { "" } else { "s" }
+            ),
+            MatchResult::WonByWickets {
+                team,
+                wickets,
+                balls_remaining,
+            } => {
+                let mut description = format!(
+                    "{team_name} won by {wickets} wicket{plural}",
+                    team_name = game_state.team(team).team_name,
+                    plural = if *wickets == 1 { "" } else { "s" }
+                );
+
+                if let Some(balls) = balls_remaining.filter(|balls| *balls > 0) {
+                    description += &format!(
+                        " with {balls} ball{plural} remaining",
+                        plural = if balls == 1 { "" } else { "s" }
+                    );
+                }
+
+                description
+            }
+            MatchResult::WonByInnings { team, runs } => format!(
+                "{team_name} won by an innings and {runs} run{plural}",
+                team_name = game_state.team(team).team_name,
+                plural = if *runs == 1 { "" } else { "s" }
+            ),
+            MatchResult::Tie => String::from("Match tied"),
+            MatchResult::NoResult => String::from("No result"),
+            MatchResult::Draw => String::from("Match drawn"),
+        }
+    }
+}
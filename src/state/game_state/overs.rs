@@ -15,15 +15,19 @@ impl Overs {
         self.balls += 1;
     }
 
-    pub fn add_ball_bowler(&mut self) {
+    pub fn add_ball_bowler(&mut self, balls_per_over: u32) {
         self.balls += 1;
 
-        if self.balls == 6 {
+        if self.balls == balls_per_over {
             self.overs += 1;
             self.balls = 0;
         }
     }
 
+    pub fn total_balls(&self, balls_per_over: u32) -> u32 {
+        self.overs * balls_per_over + self.balls
+    }
+
     pub fn end_over(&mut self) {
         self.balls = 0;
         self.overs += 1;
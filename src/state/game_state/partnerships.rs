@@ -0,0 +1,81 @@
+use crate::state::game_state::overs::Overs;
+use crate::state::game_state::team::player::Player;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub struct FallOfWicket {
+    pub wicket: u32,
+    pub runs: u32,
+    pub batter: usize,
+    pub overs: Overs,
+}
+
+impl FallOfWicket {
+    pub fn new(wicket: u32, runs: u32, batter: usize, overs: Overs) -> Self {
+        Self {
+            wicket,
+            runs,
+            batter,
+            overs,
+        }
+    }
+}
+
+// runs and balls are counted from the team and batter totals when the
+// partnership started, so every way of scoring is picked up
+#[derive(Debug, Clone)]
+pub struct Partnership {
+    pub batters: [PartnershipBatter; 2],
+    pub runs: u32,
+    pub balls: u32,
+    start_runs: u32,
+    start_balls: u32,
+}
+
+impl Partnership {
+    pub fn new(batters: [&Player; 2], team_runs: u32, team_balls: u32) -> Self {
+        Self {
+            batters: batters.map(PartnershipBatter::new),
+            runs: 0,
+            balls: 0,
+            start_runs: team_runs,
+            start_balls: team_balls,
+        }
+    }
+
+    pub fn update(&mut self, team_runs: u32, team_balls: u32, players: &[Rc<RefCell<Player>>]) {
+        self.runs = team_runs - self.start_runs;
+        self.balls = team_balls - self.start_balls;
+
+        for batter in &mut self.batters {
+            batter.update(&players[batter.order].borrow());
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartnershipBatter {
+    pub order: usize,
+    pub runs: u32,
+    pub balls: u32,
+    start_runs: u32,
+    start_balls: u32,
+}
+
+impl PartnershipBatter {
+    fn new(player: &Player) -> Self {
+        Self {
+            order: player.order,
+            runs: 0,
+            balls: 0,
+            start_runs: player.runs_scored,
+            start_balls: player.balls_faced,
+        }
+    }
+
+    fn update(&mut self, player: &Player) {
+        self.runs = player.runs_scored - self.start_runs;
+        self.balls = player.balls_faced - self.start_balls;
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use strum::{Display, EnumIter};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Runs {
+    // runs run between the wickets
+    pub runs: u32,
+    #[serde(default)]
+    pub boundary: Option<Boundary>,
+    // runs run after an overthrow that did not reach the boundary
+    #[serde(default)]
+    pub overthrows: u32,
+    // runs the umpire called short, which are not scored
+    #[serde(default)]
+    pub short_runs: u32,
+}
+
+impl Runs {
+    pub fn new(runs: u32) -> Self {
+        Self {
+            runs,
+            boundary: None,
+            overthrows: 0,
+            short_runs: 0,
+        }
+    }
+
+    pub fn with_overthrows(mut self, overthrows: u32) -> Self {
+        self.overthrows = overthrows;
+        self
+    }
+
+    pub fn with_short_runs(mut self, short_runs: u32) -> Self {
+        self.short_runs = short_runs.min(self.runs);
+        self
+    }
+
+    pub fn boundary(boundary: Boundary) -> Self {
+        Self::new(0).with_boundary(boundary)
+    }
+
+    // e.g. an overthrow reaching the rope after the batters have run
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = Some(boundary);
+        self
+    }
+
+    pub fn total_runs(&self) -> u32 {
+        self.runs - self.short_runs
+            + self.overthrows
+            + self.boundary.as_ref().map_or(0, Boundary::runs)
+    }
+
+    // short runs still count when working out which end the batters are at
+    pub fn changes_strike(&self) -> bool {
+        (self.runs + self.overthrows) % 2 == 1
+    }
+
+    pub fn is_four(&self) -> bool {
+        self.boundary == Some(Boundary::Four)
+    }
+
+    pub fn is_six(&self) -> bool {
+        self.boundary == Some(Boundary::Six)
+    }
+}
+
+impl From<u32> for Runs {
+    fn from(runs: u32) -> Self {
+        Self::new(runs)
+    }
+}
+
+// json games saved before boundaries were recorded stored runs as a number
+pub fn deserialize_saved<'de, D>(deserializer: D) -> Result<Runs, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return Runs::deserialize(deserializer);
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum SavedRuns {
+        Runs(u32),
+        Delivery(Runs),
+    }
+
+    Ok(match SavedRuns::deserialize(deserializer)? {
+        SavedRuns::Runs(runs) => Runs::from(runs),
+        SavedRuns::Delivery(runs) => runs,
+    })
+}
+
+impl std::fmt::Display for Runs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = |runs: u32| if runs == 1 { "" } else { "s" };
+        let mut parts = vec![];
+
+        if self.runs > 0 || self.boundary.is_none() {
+            parts.push(format!(
+                "{runs} run{plural}",
+                runs = self.runs,
+                plural = plural(self.runs)
+            ));
+        }
+
+        if self.overthrows > 0 {
+            parts.push(format!(
+                "{overthrows} overthrow{plural}",
+                overthrows = self.overthrows,
+                plural = plural(self.overthrows)
+            ));
+        }
+
+        if let Some(boundary) = &self.boundary {
+            parts.push(match parts.is_empty() {
+                true => boundary.to_string(),
+                false => boundary.to_string().to_lowercase(),
+            });
+        }
+
+        write!(f, "{}", parts.join(" + "))?;
+
+        if self.short_runs > 0 {
+            write!(f, " ({} short)", self.short_runs)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Display, EnumIter)]
+pub enum Boundary {
+    Four,
+    Six,
+    // overthrows to the rope are not a boundary hit by the batter
+    #[strum(to_string = "Overthrow boundary")]
+    Overthrows,
+}
+
+impl Boundary {
+    pub fn runs(&self) -> u32 {
+        match self {
+            Boundary::Four | Boundary::Overthrows => 4,
+            Boundary::Six => 6,
+        }
+    }
+}
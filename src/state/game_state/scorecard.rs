@@ -0,0 +1,193 @@
+use crate::state::game_state::innings::Innings;
+use crate::state::game_state::GameState;
+use crate::state::{Event, Page};
+use iced::widget::{button, row, scrollable, text, Column};
+use iced::Element;
+
+impl GameState {
+    pub fn scorecard_view(&self) -> Element<'_, Event> {
+        let mut content = Column::new();
+
+        for (team_type, innings) in self.all_innings() {
+            content = content.push(text(self.innings_description(&team_type, &innings)));
+            content = content.push(self.batting_card(&innings));
+            content = content.push(self.boundaries(&innings));
+            content = content.push(innings.extras.to_container());
+            content = content.push(self.fall_of_wickets(&innings));
+            content = content.push(self.partnerships(&innings));
+            content = content.push(self.bowling_card(&innings));
+        }
+
+        for team in [&self.team_a, &self.team_b] {
+            for record in &team.substitutions {
+                content = content.push(text(format!(
+                    "{team}: {on} on for {off} ({kind}, innings {innings}, {overs} ov)",
+                    team = team.team_name,
+                    on = team.players[record.substitution.player_on].borrow(),
+                    off = team.players[record.substitution.player_off].borrow(),
+                    kind = record.substitution.kind.to_string().to_lowercase(),
+                    innings = record.innings,
+                    overs = record.overs.to_string()
+                )));
+            }
+        }
+
+        for team in [&self.team_a, &self.team_b] {
+            content = content.push(text(format!("{} fielding", team.team_name)));
+
+            for player in &team.players {
+                let player = player.borrow();
+
+                if player.fielding.has_dismissals() {
+                    content = content.push(player.fielding.to_container(player.name_with_roles()));
+                }
+            }
+        }
+
+        let back_page = if self.is_match_over() {
+            Page::Result
+        } else {
+            Page::Scoring
+        };
+
+        Column::new()
+            .push(button("Back").on_press(Event::ChangePage(back_page)))
+            .push(scrollable(content))
+            .into()
+    }
+
+    fn boundaries(&self, innings: &Innings) -> Column<'_, Event> {
+        let percentage = match innings.boundary_percentage() {
+            Some(percentage) => format!(" ({percentage:.1}% of runs)"),
+            None => String::new(),
+        };
+
+        Column::new().push(text(format!(
+            "Boundaries: {fours} fours, {sixes} sixes{percentage}",
+            fours = innings.fours(),
+            sixes = innings.sixes(),
+        )))
+    }
+
+    fn fall_of_wickets(&self, innings: &Innings) -> Column<'_, Event> {
+        let fall_of_wickets = innings
+            .fall_of_wickets
+            .iter()
+            .map(|fall_of_wicket| {
+                format!(
+                    "{wicket}-{runs} ({batter}, {overs} ov)",
+                    wicket = fall_of_wicket.wicket,
+                    runs = fall_of_wicket.runs,
+                    batter = innings
+                        .batter(fall_of_wicket.batter)
+                        .map_or(String::new(), |batter| batter.short_name()),
+                    overs = fall_of_wicket.overs.to_string()
+                )
+            })
+            .collect::<Vec<String>>();
+
+        if fall_of_wickets.is_empty() {
+            return Column::new();
+        }
+
+        Column::new().push(text(format!("FoW: {}", fall_of_wickets.join(", "))))
+    }
+
+    fn partnerships(&self, innings: &Innings) -> Column<'_, Event> {
+        let mut column = Column::new();
+
+        if !innings.partnerships.is_empty() {
+            column = column.push(text("Partnerships:"));
+        }
+
+        for (i, partnership) in innings.partnerships.iter().enumerate() {
+            let batters = partnership
+                .batters
+                .iter()
+                .map(|batter| {
+                    format!(
+                        "{name} {runs} ({balls})",
+                        name = innings
+                            .batter(batter.order)
+                            .map_or(String::new(), |player| player.short_name()),
+                        runs = batter.runs,
+                        balls = batter.balls
+                    )
+                })
+                .collect::<Vec<String>>();
+
+            column = column.push(text(format!(
+                "{number}: {runs} ({balls}) - {batters}",
+                number = i + 1,
+                runs = partnership.runs,
+                balls = partnership.balls,
+                batters = batters.join(", ")
+            )));
+        }
+
+        column
+    }
+
+    fn bowling_card(&self, innings: &Innings) -> Column<'_, Event> {
+        let mut column = Column::new().push(row![
+            text("Bowler").width(200),
+            text("O").width(50),
+            text("M").width(50),
+            text("R").width(50),
+            text("W").width(50),
+            text("Econ").width(70),
+            text("0s").width(50),
+            text("Wd").width(50),
+            text("NB").width(50),
+        ]);
+
+        let mut bowlers = innings
+            .bowlers
+            .iter()
+            .filter(|bowler| bowler.bowling_order.is_some())
+            .collect::<Vec<_>>();
+        bowlers.sort_by_key(|bowler| bowler.bowling_order);
+
+        for bowler in bowlers {
+            column = column.push(bowler.to_bowling_row(self.match_config.balls_per_over));
+        }
+
+        column
+    }
+
+    fn batting_card(&self, innings: &Innings) -> Column<'_, Event> {
+        let mut column = Column::new().push(row![
+            text("Batter").width(200),
+            text("").width(250),
+            text("R").width(50),
+            text("B").width(50),
+            text("4s").width(50),
+            text("6s").width(50),
+            text("SR").width(70),
+        ]);
+
+        let mut batters = innings
+            .batters
+            .iter()
+            .filter(|batter| batter.batting_order.is_some())
+            .collect::<Vec<_>>();
+        batters.sort_by_key(|batter| batter.batting_order);
+
+        for batter in batters {
+            column = column.push(batter.to_scorecard_row(&innings.bowlers));
+        }
+
+        let did_not_bat = innings
+            .batters
+            .iter()
+            .filter(|batter| batter.batting_order.is_none() && !batter.reserve)
+            .map(|batter| batter.name_with_roles())
+            .collect::<Vec<String>>();
+
+        if !did_not_bat.is_empty() {
+            column = column.push(text(format!("Did not bat: {}", did_not_bat.join(", "))));
+        }
+
+        column
+    }
+}
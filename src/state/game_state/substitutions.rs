@@ -0,0 +1,64 @@
+use crate::state::game_state::overs::Overs;
+use crate::state::game_state::TeamType;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Substitution {
+    pub team: TeamType,
+    pub kind: SubstitutionKind,
+    pub player_off: usize,
+    pub player_on: usize,
+}
+
+impl Substitution {
+    pub fn new(
+        team: TeamType,
+        kind: SubstitutionKind,
+        player_off: usize,
+        player_on: usize,
+    ) -> Self {
+        Self {
+            team,
+            kind,
+            player_off,
+            player_on,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Display, EnumIter)]
+pub enum SubstitutionKind {
+    #[strum(to_string = "Substitute fielder")]
+    Fielder,
+    #[strum(to_string = "Concussion replacement")]
+    Concussion,
+    #[strum(to_string = "Impact player")]
+    ImpactPlayer,
+}
+
+impl SubstitutionKind {
+    // a substitute fielder can't bat or bowl, so the player they are
+    // fielding for stays in the side
+    pub fn replaces_player(&self) -> bool {
+        !matches!(self, SubstitutionKind::Fielder)
+    }
+}
+
+// a substitution along with when it happened
+#[derive(Debug, Clone)]
+pub struct SubstitutionRecord {
+    pub substitution: Substitution,
+    pub innings: usize,
+    pub overs: Overs,
+}
+
+impl SubstitutionRecord {
+    pub fn new(substitution: Substitution, innings: usize, overs: Overs) -> Self {
+        Self {
+            substitution,
+            innings,
+            overs,
+        }
+    }
+}
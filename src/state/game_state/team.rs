@@ -1,7 +1,10 @@
 pub mod player;
 
-use crate::state::game_state::extras::{Extra, ExtraType, Extras};
+use crate::state::game_state::extras::{Extra, Extras};
+use crate::state::game_state::innings::Innings;
 use crate::state::game_state::overs::Overs;
+use crate::state::game_state::partnerships::{FallOfWicket, Partnership};
+use crate::state::game_state::substitutions::SubstitutionRecord;
 use player::Player;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
@@ -15,6 +18,10 @@ pub struct Team {
     pub wickets: u32,
     pub overs: Overs,
     pub extras: Extras,
+    pub innings: Vec<Innings>,
+    pub fall_of_wickets: Vec<FallOfWicket>,
+    pub partnerships: Vec<Partnership>,
+    pub substitutions: Vec<SubstitutionRecord>,
 }
 
 impl Team {
@@ -26,25 +33,86 @@ impl Team {
             wickets: 0,
             overs: Overs::new(),
             extras: Extras::new(),
+            innings: vec![],
+            fall_of_wickets: vec![],
+            partnerships: vec![],
+            substitutions: vec![],
         }
     }
 
     pub fn add_player(&mut self, player: Player) {
+        let order = player.order;
+        let (captain, wicket_keeper) = (player.captain, player.wicket_keeper);
         self.players.push(Rc::new(RefCell::new(player)));
+
+        // a team only has one captain and one wicketkeeper
+        if captain {
+            self.set_captain(order);
+        }
+
+        if wicket_keeper {
+            self.set_wicket_keeper(order);
+        }
     }
 
-    pub fn add_extra(&mut self, extra: &Extra) {
-        let runs: u32;
+    pub fn set_captain(&mut self, order: usize) {
+        for player in &self.players {
+            let mut player = player.borrow_mut();
+            player.captain = player.order == order;
+        }
+    }
 
-        match extra.extra_type {
-            ExtraType::Wide | ExtraType::NoBall => runs = extra.runs + 1,
-            _ => runs = extra.runs,
+    pub fn set_wicket_keeper(&mut self, order: usize) {
+        for player in &self.players {
+            let mut player = player.borrow_mut();
+            player.wicket_keeper = player.order == order;
         }
+    }
 
-        self.runs += runs;
+    pub fn captain(&self) -> Option<Rc<RefCell<Player>>> {
+        self.players
+            .iter()
+            .find(|player| player.borrow().captain)
+            .map(Rc::clone)
+    }
+
+    pub fn wicket_keeper(&self) -> Option<Rc<RefCell<Player>>> {
+        self.players
+            .iter()
+            .find(|player| player.borrow().wicket_keeper)
+            .map(Rc::clone)
+    }
+
+    pub fn add_extra(&mut self, extra: &Extra) {
+        self.runs += extra.total_runs();
         self.extras.add_extra(extra);
     }
 
+    pub fn total_runs(&self) -> u32 {
+        self.innings.iter().map(|innings| innings.runs).sum()
+    }
+
+    pub fn add_penalty_runs(&mut self, runs: u32) {
+        self.runs += runs;
+        self.extras.add_penalty_runs(runs);
+    }
+
+    pub fn reset_innings(&mut self) {
+        self.runs = 0;
+        self.wickets = 0;
+        self.overs = Overs::new();
+        self.extras = Extras::new();
+        self.fall_of_wickets.clear();
+        self.partnerships.clear();
+        self.reset_players();
+    }
+
+    pub fn reset_players(&mut self) {
+        for player in &self.players {
+            player.borrow_mut().reset_innings();
+        }
+    }
+
     pub fn next_bowling_order(&self) -> usize {
         self.players
             .iter()
@@ -56,6 +124,29 @@ impl Team {
             + 1
     }
 
+    pub fn playing_players(&self) -> Vec<Rc<RefCell<Player>>> {
+        self.players
+            .iter()
+            .filter(|player| !player.borrow().reserve)
+            .map(Rc::clone)
+            .collect()
+    }
+
+    pub fn reserves(&self) -> Vec<Rc<RefCell<Player>>> {
+        self.players
+            .iter()
+            .filter(|player| player.borrow().reserve)
+            .map(Rc::clone)
+            .collect()
+    }
+
+    // a player replaced during the match can't come back on
+    pub fn was_replaced(&self, order: usize) -> bool {
+        self.substitutions.iter().any(|record| {
+            record.substitution.kind.replaces_player() && record.substitution.player_off == order
+        })
+    }
+
     pub fn bowled_players_in_order(&self) -> Vec<Rc<RefCell<Player>>> {
         let mut players: Vec<Rc<RefCell<Player>>> = self
             .players
@@ -76,7 +167,7 @@ impl Team {
     pub fn not_bowled_players(&self) -> Vec<Rc<RefCell<Player>>> {
         self.players
             .iter()
-            .filter(|player| player.borrow().bowling_order == None)
+            .filter(|player| player.borrow().bowling_order == None && !player.borrow().reserve)
             .map(|player| Rc::clone(player))
             .collect()
     }
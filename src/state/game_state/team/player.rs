@@ -1,8 +1,9 @@
-use crate::state::game_state::extras::{Extra, ExtraType, Extras};
+use crate::state::game_state::extras::{Extra, Extras};
+use crate::state::game_state::fielding::Fielding;
 use crate::state::game_state::overs::Overs;
 use crate::state::game_state::wickets::{HowOut, WicketDetails};
 use crate::state::Event;
-use iced::widget::{container, text, Container};
+use iced::widget::{container, row, text, Container, Row};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
@@ -14,13 +15,30 @@ pub struct Player {
     pub wicket_details: Option<WicketDetails>,
     pub runs_scored: u32,
     pub balls_faced: u32,
+    #[serde(default)]
+    pub fours: u32,
+    #[serde(default)]
+    pub sixes: u32,
     pub runs_conceded: u32,
     pub wickets_taken: u32,
     pub overs_bowled: Overs,
+    #[serde(default)]
+    pub maidens: u32,
+    #[serde(default)]
+    pub dot_balls: u32,
     extras: Extras,
     pub order: usize,
     pub batting_order: Option<usize>,
     pub bowling_order: Option<usize>,
+    #[serde(default)]
+    pub captain: bool,
+    #[serde(default)]
+    pub wicket_keeper: bool,
+    // squad members outside the playing side
+    #[serde(default)]
+    pub reserve: bool,
+    #[serde(default)]
+    pub fielding: Fielding,
 }
 
 impl Player {
@@ -33,30 +51,176 @@ impl Player {
             wicket_details: None,
             runs_scored: 0,
             balls_faced: 0,
+            fours: 0,
+            sixes: 0,
             runs_conceded: 0,
             wickets_taken: 0,
             overs_bowled: Overs::new(),
+            maidens: 0,
+            dot_balls: 0,
             extras: Extras::new(),
             order,
             batting_order: None,
             bowling_order: None,
+            captain: false,
+            wicket_keeper: false,
+            reserve: false,
+            fielding: Fielding::new(),
         }
     }
 
-    pub fn add_extra(&mut self, extra: &Extra) {
-        match extra.extra_type {
-            ExtraType::Bye | ExtraType::LegBye => self.overs_bowled.add_ball_bowler(),
-            _ => (),
+    pub fn with_roles(mut self, captain: bool, wicket_keeper: bool) -> Self {
+        self.captain = captain;
+        self.wicket_keeper = wicket_keeper;
+        self
+    }
+
+    pub fn with_reserve(mut self, reserve: bool) -> Self {
+        self.reserve = reserve;
+        self
+    }
+
+    pub fn add_extra(&mut self, extra: &Extra, balls_per_over: u32) {
+        if extra.is_legal_ball() {
+            self.overs_bowled.add_ball_bowler(balls_per_over);
+            // byes and leg byes are not scored against the bowler
+            self.dot_balls += 1;
         }
 
+        self.runs_conceded += extra.bowler_runs();
         self.extras.add_extra(extra);
     }
+
+    pub fn strike_rate(&self) -> Option<f64> {
+        if self.balls_faced == 0 {
+            return None;
+        }
+
+        Some(self.runs_scored as f64 * 100.0 / self.balls_faced as f64)
+    }
+
+    pub fn economy(&self, balls_per_over: u32) -> Option<f64> {
+        let balls = self.overs_bowled.total_balls(balls_per_over);
+
+        if balls == 0 {
+            return None;
+        }
+
+        Some(self.runs_conceded as f64 * balls_per_over as f64 / balls as f64)
+    }
+
+    pub fn reset_innings(&mut self) {
+        self.how_out = HowOut::DidNotBat;
+        self.wicket_details = None;
+        self.runs_scored = 0;
+        self.balls_faced = 0;
+        self.fours = 0;
+        self.sixes = 0;
+        self.runs_conceded = 0;
+        self.wickets_taken = 0;
+        self.overs_bowled = Overs::new();
+        self.maidens = 0;
+        self.dot_balls = 0;
+        self.extras = Extras::new();
+        self.batting_order = None;
+        self.bowling_order = None;
+    }
 }
 
 impl Player {
     // views
     pub fn to_container<'a>(self) -> Container<'a, Event> {
-        container(text(self.to_string()))
+        match self.reserve {
+            true => container(text(format!("{} (reserve)", self.name_with_roles()))),
+            false => container(text(self.name_with_roles())),
+        }
+    }
+
+    pub fn to_scorecard_row<'a>(&self, fielders: &[Player]) -> Row<'a, Event> {
+        let strike_rate = match self.strike_rate() {
+            Some(strike_rate) => format!("{strike_rate:.2}"),
+            None => String::from("-"),
+        };
+
+        row![
+            text(format!(
+                "{position}. {name}",
+                position = self.batting_order.map_or(0, |order| order + 1),
+                name = self.name_with_roles()
+            ))
+            .width(200),
+            text(self.dismissal(fielders)).width(250),
+            text(self.runs_scored).width(50),
+            text(self.balls_faced).width(50),
+            text(self.fours).width(50),
+            text(self.sixes).width(50),
+            text(strike_rate).width(70),
+        ]
+    }
+
+    // dismissal as it appears on a scorecard, e.g. "c Smith b Jones"
+    pub fn dismissal(&self, fielders: &[Player]) -> String {
+        let details = self.wicket_details.as_ref();
+        let name = |order: Option<usize>, substitute: bool| {
+            order
+                .and_then(|order| fielders.iter().find(|player| player.order == order))
+                .map_or(String::new(), |player| match substitute {
+                    true => format!("sub ({})", player.short_name()),
+                    false => player.short_name(),
+                })
+        };
+        let caught_and_bowled = details
+            .is_none_or(|details| details.fielder.is_none() || details.fielder == details.bowler);
+        let bowler = name(details.and_then(|details| details.bowler), false);
+        let fielder = name(
+            details.and_then(|details| details.fielder),
+            details.is_some_and(|details| details.substitute_fielder),
+        );
+        let assist = name(
+            details.and_then(|details| details.assist),
+            details.is_some_and(|details| details.substitute_assist),
+        );
+
+        match self.how_out {
+            HowOut::DidNotBat => String::from("did not bat"),
+            HowOut::NotOut => String::from("not out"),
+            HowOut::Bowled => format!("b {bowler}"),
+            HowOut::Lbw => format!("lbw b {bowler}"),
+            HowOut::Caught if caught_and_bowled => {
+                format!("c & b {bowler}")
+            }
+            HowOut::Caught => format!("c {fielder} b {bowler}"),
+            HowOut::RunOut if fielder.is_empty() => String::from("run out"),
+            HowOut::RunOut if assist.is_empty() => format!("run out ({fielder})"),
+            HowOut::RunOut => format!("run out ({assist}/{fielder})"),
+            HowOut::Stumped => format!("st {fielder} b {bowler}"),
+            HowOut::HitWicket => format!("hit wicket b {bowler}"),
+            HowOut::HitBallTwice => String::from("hit the ball twice"),
+            HowOut::HandledBall => String::from("handled the ball"),
+            HowOut::ObstructedField => String::from("obstructing the field"),
+            HowOut::TimedOut => String::from("timed out"),
+            HowOut::RetiredHurt => String::from("retired hurt"),
+            HowOut::RetiredNotOut => String::from("retired not out"),
+        }
+    }
+
+    // surname with the wicketkeeper marker, as used in dismissals
+    pub fn short_name(&self) -> String {
+        format!(
+            "{keeper}{name}",
+            keeper = if self.wicket_keeper { "\u{2020}" } else { "" },
+            name = self.last_name
+        )
+    }
+
+    // scorecard style name, e.g. "†Jane Lee (c)"
+    pub fn name_with_roles(&self) -> String {
+        format!(
+            "{keeper}{name}{captain}",
+            keeper = if self.wicket_keeper { "\u{2020}" } else { "" },
+            name = self,
+            captain = if self.captain { " (c)" } else { "" }
+        )
     }
 
     pub fn to_batting_container<'a>(self) -> Container<'a, Event> {
@@ -70,14 +234,33 @@ impl Player {
 
     pub fn to_bowling_container<'a>(self) -> Container<'a, Event> {
         container(text(format!(
-            "{name}: {wickets}/{runs} ({overs}.{balls})",
+            "{name}: {overs}-{maidens}-{runs}-{wickets}",
             name = self.to_string(),
-            wickets = self.wickets_taken,
+            overs = self.overs_bowled.to_string(),
+            maidens = self.maidens,
             runs = self.runs_conceded,
-            overs = self.overs_bowled.overs,
-            balls = self.overs_bowled.balls,
+            wickets = self.wickets_taken,
         )))
     }
+
+    pub fn to_bowling_row<'a>(&self, balls_per_over: u32) -> Row<'a, Event> {
+        let economy = match self.economy(balls_per_over) {
+            Some(economy) => format!("{economy:.2}"),
+            None => String::from("-"),
+        };
+
+        row![
+            text(self.name_with_roles()).width(200),
+            text(self.overs_bowled.to_string()).width(50),
+            text(self.maidens).width(50),
+            text(self.runs_conceded).width(50),
+            text(self.wickets_taken).width(50),
+            text(economy).width(70),
+            text(self.dot_balls).width(50),
+            text(self.extras.wide_deliveries()).width(50),
+            text(self.extras.no_balls()).width(50),
+        ]
+    }
 }
 
 impl Display for Player {
@@ -86,8 +269,21 @@ impl Display for Player {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum PlayerType {
     A,
     B,
 }
+
+// the player who is really at the crease in place of the batter entered
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatterCorrection {
+    pub batter: PlayerType,
+    pub player: usize,
+}
+
+impl BatterCorrection {
+    pub fn new(batter: PlayerType, player: usize) -> Self {
+        Self { batter, player }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::state::game_state::extras::{Extra, ExtraType};
+use crate::state::game_state::PlayerType;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter};
 
@@ -30,15 +32,114 @@ pub enum HowOut {
     RetiredNotOut,
 }
 
+impl HowOut {
+    pub fn rules(&self) -> DismissalRules {
+        // (credits bowler, counts as ball, counts as wicket)
+        let (credits_bowler, counts_as_ball, counts_as_wicket) = match self {
+            HowOut::DidNotBat | HowOut::NotOut => (false, false, false),
+            HowOut::Bowled => (true, true, true),
+            HowOut::Lbw => (true, true, true),
+            HowOut::Caught => (true, true, true),
+            HowOut::RunOut => (false, true, true),
+            HowOut::Stumped => (true, true, true),
+            HowOut::HitWicket => (true, true, true),
+            HowOut::HitBallTwice => (false, true, true),
+            HowOut::HandledBall => (false, true, true),
+            HowOut::ObstructedField => (false, true, true),
+            HowOut::TimedOut => (false, false, true),
+            HowOut::RetiredHurt => (false, false, false),
+            HowOut::RetiredNotOut => (false, false, false),
+        };
+
+        DismissalRules {
+            credits_bowler,
+            counts_as_ball,
+            counts_as_wicket,
+        }
+    }
+
+    pub fn is_allowed_on_free_hit(&self) -> bool {
+        matches!(
+            self,
+            HowOut::RunOut
+                | HowOut::HitBallTwice
+                | HowOut::HandledBall
+                | HowOut::ObstructedField
+                | HowOut::TimedOut
+                | HowOut::RetiredHurt
+                | HowOut::RetiredNotOut
+        )
+    }
+
+    pub fn is_retirement(&self) -> bool {
+        matches!(self, HowOut::RetiredHurt | HowOut::RetiredNotOut)
+    }
+
+    pub fn is_allowed_on_extra(&self, extra_type: &ExtraType) -> bool {
+        match extra_type {
+            ExtraType::Wide => matches!(
+                self,
+                HowOut::Stumped
+                    | HowOut::HitWicket
+                    | HowOut::RunOut
+                    | HowOut::HandledBall
+                    | HowOut::ObstructedField
+            ),
+            ExtraType::NoBall => matches!(
+                self,
+                HowOut::RunOut
+                    | HowOut::HitBallTwice
+                    | HowOut::HandledBall
+                    | HowOut::ObstructedField
+            ),
+            ExtraType::Bye | ExtraType::LegBye => matches!(
+                self,
+                HowOut::RunOut | HowOut::HandledBall | HowOut::ObstructedField
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DismissalRules {
+    pub credits_bowler: bool,
+    pub counts_as_ball: bool,
+    pub counts_as_wicket: bool,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WicketDetails {
     pub bowler: Option<usize>,
     pub fielder: Option<usize>,
+    #[serde(default)]
+    pub assist: Option<usize>,
+    // whether the fielders were on as substitutes at the time
+    #[serde(default)]
+    pub substitute_fielder: bool,
+    #[serde(default)]
+    pub substitute_assist: bool,
 }
 
 impl WicketDetails {
     pub fn new(bowler: Option<usize>, fielder: Option<usize>) -> Self {
-        Self { bowler, fielder }
+        Self {
+            bowler,
+            fielder,
+            assist: None,
+            substitute_fielder: false,
+            substitute_assist: false,
+        }
+    }
+
+    pub fn with_assist(mut self, assist: Option<usize>) -> Self {
+        self.assist = assist;
+        self
+    }
+
+    pub fn with_substitutes(mut self, fielder: bool, assist: bool) -> Self {
+        self.substitute_fielder = fielder;
+        self.substitute_assist = assist;
+        self
     }
 }
 
@@ -47,6 +148,10 @@ pub struct WicketEvent {
     pub how_out: HowOut,
     pub bowler: Option<usize>,
     pub fielder: Option<usize>,
+    #[serde(default)]
+    pub run_out: Option<RunOut>,
+    #[serde(default)]
+    pub extra: Option<Extra>,
 }
 
 impl WicketEvent {
@@ -55,6 +160,80 @@ impl WicketEvent {
             how_out,
             bowler,
             fielder,
+            run_out: None,
+            extra: None,
+        }
+    }
+
+    // runs completed off a wide or no ball are recorded on the extra
+    pub fn with_extra(mut self, extra: Extra) -> Self {
+        self.extra = Some(extra);
+        self
+    }
+
+    pub fn with_run_out(mut self, run_out: RunOut) -> Self {
+        self.run_out = Some(run_out);
+        self
+    }
+
+    pub fn runs_completed(&self) -> u32 {
+        match (&self.run_out, &self.extra) {
+            (Some(run_out), None) => run_out.runs_completed,
+            _ => 0,
         }
     }
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Retirement {
+    pub batter: PlayerType,
+    pub how_out: HowOut,
+}
+
+impl Retirement {
+    pub fn new(batter: PlayerType, how_out: HowOut) -> Self {
+        Self { batter, how_out }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RunOut {
+    pub runs_completed: u32,
+    pub batter: PlayerType,
+    pub end: CreaseEnd,
+    // the fielder who threw the ball to the one who broke the wicket, if it
+    // was not a direct hit
+    pub assist: Option<usize>,
+    #[serde(default)]
+    pub direct_hit: bool,
+}
+
+impl RunOut {
+    pub fn new(runs_completed: u32, batter: PlayerType, end: CreaseEnd) -> Self {
+        Self {
+            runs_completed,
+            batter,
+            end,
+            assist: None,
+            direct_hit: false,
+        }
+    }
+
+    pub fn with_assist(mut self, assist: usize) -> Self {
+        self.assist = Some(assist);
+        self
+    }
+
+    pub fn with_direct_hit(mut self, direct_hit: bool) -> Self {
+        self.direct_hit = direct_hit;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Display, EnumIter)]
+pub enum CreaseEnd {
+    #[strum(to_string = "Striker's end")]
+    Striker,
+    #[strum(to_string = "Non-striker's end")]
+    NonStriker,
+}